@@ -1,7 +1,7 @@
 use base64::{engine::general_purpose::URL_SAFE, Engine};
 use criterion::{criterion_group, criterion_main, Criterion};
 use dash_rs::{
-    model::level::{Level, LevelData},
+    model::level::{lazy::LazyObjects, Level, LevelData},
     GJFormat, Thunk,
 };
 use flate2::read::GzDecoder;
@@ -71,11 +71,30 @@ pub fn decoding_spacial_rend_benchmark(c: &mut Criterion) {
     });
 }
 
+pub fn lazy_spacial_rend_benchmark(c: &mut Criterion) {
+    let response = read_to_string("./benches/data/40292513_special_rend_gjdownload_response").unwrap();
+
+    c.bench_function("count spacial rend objects lazily", |b| {
+        b.iter(|| {
+            let level: Level<LevelData> = Level::from_gj_str(&response).unwrap();
+            match level.level_data.level_data {
+                Thunk::Unprocessed(unprocessed) => {
+                    let lazy = LazyObjects::from_encoded(&unprocessed).unwrap();
+
+                    lazy.objects().filter(|object| object.is_ok()).count()
+                },
+                Thunk::Processed(_) => unreachable!(),
+            }
+        })
+    });
+}
+
 criterion_group!(
     benches,
     ocular_miracle_benchmark,
     spacial_rend_benchmark,
     decoding_spacial_rend_benchmark,
-    decoding_ocular_miracle_benchmark
+    decoding_ocular_miracle_benchmark,
+    lazy_spacial_rend_benchmark
 );
 criterion_main!(benches);
@@ -0,0 +1,147 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    DataEnum, Error, Fields, LitInt, Meta, MetaList, Result, Token, Type,
+};
+
+/// A C-like enum mapped to some integer type.
+///
+/// Each unit variant is mapped to the value given in its `#[dash(value = ...)]` attribute, and
+/// exactly one variant must have a single unnamed field, holding all values not mapped to any other
+/// variant. The type of that field is the integer type the enum is mapped to.
+pub struct InternalEnum {
+    pub name: Ident,
+    pub variants: Vec<(Ident, VariantValue)>,
+    pub catch_all: Ident,
+    pub repr: Type,
+}
+
+/// The (possibly negative) integer literal a unit variant is mapped to
+pub struct VariantValue {
+    minus: Option<Token![-]>,
+    value: LitInt,
+}
+
+impl Parse for VariantValue {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.parse::<Ident>()?;
+
+        if key != "value" {
+            return Err(Error::new(key.span(), "expected `value = ...`"));
+        }
+
+        let _ = input.parse::<Token![=]>()?;
+
+        Ok(VariantValue {
+            minus: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for VariantValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.minus.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+impl InternalEnum {
+    pub fn new(name: Ident, data: DataEnum) -> Result<InternalEnum> {
+        let mut variants = Vec::new();
+        let mut catch_all = None;
+
+        for variant in data.variants {
+            let span = variant.span();
+            let mut value = None;
+
+            for attr in variant.attrs {
+                let Meta::List(MetaList { path, tokens, .. }) = attr.meta else {
+                    continue;
+                };
+
+                if !path.is_ident("dash") {
+                    continue;
+                }
+
+                if value.replace(syn::parse2::<VariantValue>(tokens)?).is_some() {
+                    return Err(Error::new(span, "duplicate #[dash(value = ...)] attribute"));
+                }
+            }
+
+            match (variant.fields, value) {
+                (Fields::Unit, Some(value)) => variants.push((variant.ident, value)),
+                (Fields::Unit, None) => return Err(Error::new(span, "missing #[dash(value = ...)] attribute")),
+                (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 && catch_all.is_none() => {
+                    catch_all = Some((variant.ident, fields.unnamed.into_iter().next().unwrap().ty))
+                },
+                (Fields::Unnamed(_), Some(_)) => return Err(Error::new(span, "the catch-all variant cannot have a value")),
+                _ => {
+                    return Err(Error::new(
+                        span,
+                        "#[derive(Dash)] on enums only supports unit variants and a single catch-all variant with one unnamed field",
+                    ))
+                },
+            }
+        }
+
+        let Some((catch_all, repr)) = catch_all else {
+            return Err(Error::new(
+                name.span(),
+                "#[derive(Dash)] on enums requires a catch-all variant such as `Unknown(u8)`",
+            ));
+        };
+
+        Ok(InternalEnum {
+            name,
+            variants,
+            catch_all,
+            repr,
+        })
+    }
+}
+
+impl ToTokens for InternalEnum {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let InternalEnum { name, catch_all, repr, .. } = self;
+        let variants = self.variants.iter().map(|(variant, _)| variant).collect::<Vec<_>>();
+        let values = self.variants.iter().map(|(_, value)| value).collect::<Vec<_>>();
+
+        tokens.extend(quote! {
+            const _: () = {
+                impl From<#repr> for #name {
+                    fn from(value: #repr) -> Self {
+                        match value {
+                            #(#values => #name::#variants,)*
+                            unknown => #name::#catch_all(unknown),
+                        }
+                    }
+                }
+
+                impl From<#name> for #repr {
+                    fn from(value: #name) -> Self {
+                        match value {
+                            #(#name::#variants => #values,)*
+                            #name::#catch_all(unknown) => unknown,
+                        }
+                    }
+                }
+
+                impl crate::serde::InternalProxy for #name {
+                    type DeserializeProxy = #repr;
+                    type SerializeProxy<'a> = #repr where Self: 'a;
+
+                    fn to_serialize_proxy(&self) -> #repr {
+                        <#repr>::from(*self)
+                    }
+
+                    fn from_deserialize_proxy(from: #repr) -> Self {
+                        <#name>::from(from)
+                    }
+                }
+            };
+        })
+    }
+}
@@ -1,31 +1,121 @@
 use std::convert::TryFrom;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{
-    parse::{discouraged::Speculative, Parse},
+    parenthesized,
+    parse::{discouraged::Speculative, Parse, ParseStream},
     parse_quote,
     spanned::Spanned,
-    Error, Field, Lifetime, LitInt, LitStr, Meta, MetaList, Result, Token, Type,
+    Error, Field, Lifetime, LitInt, LitStr, Meta, MetaList, Path, Result, Token, Type,
 };
 
 use crate::utils;
 
+#[derive(Clone)]
 pub enum FieldMapping {
     /// An internal field that is mapped 1:1 to an API field
     OneToOne(OneToOne),
 
+    /// An API field computed from multiple internal fields via user-supplied conversion functions
+    Composite(Composite),
+
     /// An API field that has no corresponding internal field.
     ///
     /// These get initialized to [`Default::default`] during deserialization.
     NoIndex { field: Ident },
+
+    /// An API field collecting all indices not mapped to any other field.
+    ///
+    /// During deserialization, all unknown indices are collected into it, and during serialization
+    /// they are written back out after all mapped fields.
+    UnknownFields { field: Ident },
 }
 
+#[derive(Clone)]
 pub enum LitIndex {
     Int(LitInt),
     Str(LitStr),
 }
 
+impl LitIndex {
+    pub fn value(&self) -> String {
+        match self {
+            LitIndex::Int(lit_int) => lit_int.base10_digits().to_string(),
+            LitIndex::Str(lit_str) => lit_str.value(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            LitIndex::Int(lit_int) => lit_int.span(),
+            LitIndex::Str(lit_str) => lit_str.span(),
+        }
+    }
+
+    fn internal_name(&self) -> Ident {
+        format_ident!("index_{}", self.value())
+    }
+
+    fn local_name(&self) -> Ident {
+        format_ident!("__index_{}", self.value())
+    }
+}
+
+impl Parse for LitIndex {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(LitInt) {
+            input.parse().map(LitIndex::Int)
+        } else if lookahead.peek(LitStr) {
+            input.parse().map(LitIndex::Str)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// The type used to represent the given API type in the internal serialization struct
+fn ser_type(api_type: &Type, lifetime: &Lifetime) -> Type {
+    parse_quote! {
+        <#api_type as crate::serde::InternalProxy>::SerializeProxy<#lifetime>
+    }
+}
+
+/// The type used to represent the given API type in the internal deserialization struct
+fn de_type(api_type: &Type) -> Type {
+    parse_quote! {
+        <#api_type as crate::serde::InternalProxy>::DeserializeProxy
+    }
+}
+
+/// Generates the declaration of a field in one of the internal structs
+fn field_tokens(index: &LitIndex, ty: &Type, passthrough: &[TokenStream]) -> TokenStream {
+    let serde_name = index.value();
+    let field_name = index.internal_name();
+
+    if utils::type_contains_lifetime(ty) {
+        quote! {
+            #[serde(rename = #serde_name)]
+            #[serde(borrow)]
+            #(
+                #[serde(#passthrough)]
+            )*
+            pub #field_name: #ty,
+        }
+    } else {
+        quote! {
+            #[serde(rename = #serde_name)]
+            #(
+                #[serde(#passthrough)]
+            )*
+            pub #field_name: #ty,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct OneToOne {
     /// The index of this field in the internal data format
     pub index: LitIndex,
@@ -41,81 +131,152 @@ pub struct OneToOne {
 }
 
 impl OneToOne {
-    fn ser_type(&self, lifetime: &Lifetime) -> Type {
-        let api_type = &self.api_type;
+    pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> proc_macro2::TokenStream {
+        field_tokens(&self.index, &ser_type(&self.api_type, lifetime), &self.passthrough)
+    }
+
+    pub fn de_field_tokens(&self) -> proc_macro2::TokenStream {
+        field_tokens(&self.index, &de_type(&self.api_type), &self.passthrough)
+    }
 
-        parse_quote! {
-            <#api_type as crate::serde::InternalProxy>::SerializeProxy<#lifetime>
+    pub fn serialize(&self) -> proc_macro2::TokenStream {
+        let field_name = self.index.internal_name();
+        let field = &self.field;
+
+        quote! {
+            #field_name: self.#field.to_serialize_proxy(),
         }
     }
 
-    fn de_type(&self) -> Type {
+    pub fn deserialize(&self) -> proc_macro2::TokenStream {
+        let field_name = self.index.internal_name();
+        let field = &self.field;
         let api_type = &self.api_type;
-        parse_quote! {
-            <#api_type as crate::serde::InternalProxy>::DeserializeProxy
+
+        quote! {
+            #field: <#api_type>::from_deserialize_proxy(internal.#field_name),
         }
     }
+}
 
-    fn index(&self) -> String {
-        match &self.index {
-            LitIndex::Int(lit_int) => lit_int.base10_digits().to_string(),
-            LitIndex::Str(lit_str) => lit_str.value(),
+/// A raw index an [`Composite`] field is computed from
+#[derive(Clone)]
+pub enum RawIndex {
+    /// An index that only exists to construct the composite field. It gets its own field in the
+    /// internal structs, whose type is given by the `InternalProxy` implementation of `ty` (just like
+    /// for [`OneToOne`] fields).
+    Owned {
+        index: LitIndex,
+        ty: Box<Type>,
+        passthrough: Vec<TokenStream>,
+    },
+
+    /// An index that is mapped 1:1 to some other API field, but whose value is additionally needed
+    /// to construct the composite field. It is only read during deserialization, meaning the
+    /// deserialization proxy of the other field must be `Copy`.
+    Dependency(LitIndex),
+}
+
+impl RawIndex {
+    fn index(&self) -> &LitIndex {
+        match self {
+            RawIndex::Owned { index, .. } | RawIndex::Dependency(index) => index,
         }
     }
+}
 
-    fn internal_name(&self) -> Ident {
-        format_ident!("index_{}", self.index())
+#[derive(Clone)]
+pub struct Composite {
+    /// The API field constructed from the raw indices
+    pub field: Ident,
+
+    /// The type of the API field
+    pub api_type: Type,
+
+    /// The raw indices, in the order they are passed to the `from` function
+    pub raws: Vec<RawIndex>,
+
+    /// Path to the function constructing the API field from the raw indices. It gets passed the
+    /// deserialization proxies of all raw indices.
+    pub from: Path,
+
+    /// Whether `from` returns a `Result` whose error should be turned into a deserialization error
+    pub fallible: bool,
+
+    /// Path to the function turning a reference to the API field back into the serialization proxies
+    /// of the owned raw indices
+    pub into: Path,
+}
+
+impl Composite {
+    fn owned(&self) -> impl Iterator<Item = &LitIndex> {
+        self.raws.iter().filter_map(|raw| match raw {
+            RawIndex::Owned { index, .. } => Some(index),
+            RawIndex::Dependency(_) => None,
+        })
     }
 
-    fn field_tokens(&self, ty: Type) -> proc_macro2::TokenStream {
-        let serde_name = self.index();
-        let field_name = self.internal_name();
-        let passthrough = &self.passthrough;
+    fn field_tokens(&self, proxy_type: impl Fn(&Type) -> Type) -> TokenStream {
+        let fields = self.raws.iter().filter_map(|raw| match raw {
+            RawIndex::Owned { index, ty, passthrough } => Some(field_tokens(index, &proxy_type(ty), passthrough)),
+            RawIndex::Dependency(_) => None,
+        });
 
-        if utils::type_contains_lifetime(&ty) {
-            quote! {
-                #[serde(rename = #serde_name)]
-                #[serde(borrow)]
-                #(
-                    #[serde(#passthrough)]
-                )*
-                pub #field_name: #ty,
-            }
-        } else {
-            quote! {
-                #[serde(rename = #serde_name)]
-                #(
-                    #[serde(#passthrough)]
-                )*
-                pub #field_name: #ty,
-            }
+        quote! {
+            #(#fields)*
         }
     }
 
-    pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> proc_macro2::TokenStream {
-        self.field_tokens(self.ser_type(lifetime))
+    pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> TokenStream {
+        self.field_tokens(|ty| ser_type(ty, lifetime))
     }
 
-    pub fn de_field_tokens(&self) -> proc_macro2::TokenStream {
-        self.field_tokens(self.de_type())
+    pub fn de_field_tokens(&self) -> TokenStream {
+        self.field_tokens(de_type)
     }
 
-    pub fn serialize(&self) -> proc_macro2::TokenStream {
-        let field_name = self.internal_name();
+    pub fn serialize_prelude(&self) -> TokenStream {
+        let into = &self.into;
         let field = &self.field;
+        let locals = self.owned().map(LitIndex::local_name).collect::<Vec<_>>();
+
+        // A single raw index is returned as-is instead of as a 1-tuple
+        if let [local] = &locals[..] {
+            quote! {
+                let #local = #into(&self.#field);
+            }
+        } else {
+            quote! {
+                let (#(#locals),*) = #into(&self.#field);
+            }
+        }
+    }
+
+    pub fn serialize(&self) -> TokenStream {
+        let field_names = self.owned().map(LitIndex::internal_name);
+        let locals = self.owned().map(LitIndex::local_name);
 
         quote! {
-            #field_name: self.#field.to_serialize_proxy(),
+            #(#field_names: #locals,)*
         }
     }
 
-    pub fn deserialize(&self) -> proc_macro2::TokenStream {
-        let field_name = self.internal_name();
+    pub fn deserialize(&self) -> TokenStream {
+        let from = &self.from;
         let field = &self.field;
-        let api_type = &self.api_type;
+        let field_names = self.raws.iter().map(|raw| raw.index().internal_name());
 
-        quote! {
-            #field: <#api_type>::from_deserialize_proxy(internal.#field_name),
+        if self.fallible {
+            quote! {
+                #field: match #from(#(internal.#field_names),*) {
+                    Ok(value) => value,
+                    Err(err) => return Err(<D::Error as serde::de::Error>::custom(err)),
+                },
+            }
+        } else {
+            quote! {
+                #field: #from(#(internal.#field_names),*),
+            }
         }
     }
 }
@@ -124,34 +285,104 @@ impl FieldMapping {
     pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.ser_field_tokens(lifetime),
+            FieldMapping::Composite(inner) => inner.ser_field_tokens(lifetime),
             FieldMapping::NoIndex { .. } => quote!(),
+            FieldMapping::UnknownFields { .. } => quote! {
+                #[serde(flatten)]
+                pub __unknown_fields: &#lifetime crate::serde::UnknownFields<#lifetime>,
+            },
         }
     }
 
-    pub fn de_field_tokens(&self) -> TokenStream {
+    pub fn de_field_tokens(&self, lifetime: &Lifetime) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.de_field_tokens(),
+            FieldMapping::Composite(inner) => inner.de_field_tokens(),
             FieldMapping::NoIndex { .. } => quote!(),
+            // The unknown fields are collected outside of the internal struct. However, the lifetime
+            // of the struct might not be used by any other field, so mark it as used.
+            FieldMapping::UnknownFields { .. } => quote! {
+                #[serde(skip)]
+                pub __unknown_fields: std::marker::PhantomData<&#lifetime ()>,
+            },
+        }
+    }
+
+    /// Statements that need to run before the internal serialization struct is constructed
+    pub fn serialize_prelude(&self) -> TokenStream {
+        match self {
+            FieldMapping::Composite(inner) => inner.serialize_prelude(),
+            _ => quote!(),
         }
     }
 
     pub fn serialize(&self) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.serialize(),
+            FieldMapping::Composite(inner) => inner.serialize(),
             FieldMapping::NoIndex { .. } => quote!(),
+            FieldMapping::UnknownFields { field } => quote! {
+                __unknown_fields: &self.#field,
+            },
         }
     }
 
     pub fn deserialize(&self) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.deserialize(),
+            FieldMapping::Composite(inner) => inner.deserialize(),
             FieldMapping::NoIndex { field } => quote! {
                 #field: Default::default(),
             },
+            FieldMapping::UnknownFields { field } => quote! {
+                #field: __unknown_fields,
+            },
+        }
+    }
+
+    pub fn is_unknown_fields(&self) -> bool {
+        matches!(self, FieldMapping::UnknownFields { .. })
+    }
+
+    /// The indices this field maps to, together with a flag indicating whether the index is only
+    /// read as a dependency of some composite field
+    pub fn indices(&self) -> Vec<(&LitIndex, bool)> {
+        match self {
+            FieldMapping::OneToOne(inner) => vec![(&inner.index, false)],
+            FieldMapping::Composite(inner) => inner
+                .raws
+                .iter()
+                .map(|raw| (raw.index(), matches!(raw, RawIndex::Dependency(_))))
+                .collect(),
+            FieldMapping::NoIndex { .. } | FieldMapping::UnknownFields { .. } => Vec::new(),
+        }
+    }
+
+    /// Replaces the generic type parameters in all types of this mapping
+    pub fn substitute_types(&mut self, substitutions: &[(Ident, Type)]) {
+        match self {
+            FieldMapping::OneToOne(inner) => utils::substitute_types(&mut inner.api_type, substitutions),
+            FieldMapping::Composite(inner) => {
+                utils::substitute_types(&mut inner.api_type, substitutions);
+
+                for raw in &mut inner.raws {
+                    if let RawIndex::Owned { ty, .. } = raw {
+                        utils::substitute_types(ty, substitutions)
+                    }
+                }
+            },
+            FieldMapping::NoIndex { .. } | FieldMapping::UnknownFields { .. } => (),
         }
     }
 }
 
+/// The conversion functions given in a `#[dash(composite(...))]` attribute
+struct Conversion {
+    from: Path,
+    fallible: bool,
+    into: Path,
+}
+
 #[derive(Default)]
 enum FieldMappingBuilder {
     #[default]
@@ -160,7 +391,12 @@ enum FieldMappingBuilder {
         index: Option<LitIndex>,
         passthrough: Vec<TokenStream>,
     },
+    Composite {
+        conversion: Option<Conversion>,
+        raws: Vec<RawIndex>,
+    },
     NoIndex,
+    UnknownFields,
 }
 
 impl FieldMappingBuilder {
@@ -191,6 +427,14 @@ impl FieldMappingBuilder {
         true
     }
 
+    fn unknown_fields(&mut self) -> bool {
+        match std::mem::take(self) {
+            FieldMappingBuilder::Initial => *self = FieldMappingBuilder::UnknownFields,
+            _ => return false,
+        }
+        true
+    }
+
     fn with_passthrough(&mut self, tokens: TokenStream) -> bool {
         match std::mem::take(self) {
             FieldMappingBuilder::Initial => {
@@ -203,7 +447,44 @@ impl FieldMappingBuilder {
                 passthrough.push(tokens);
                 *self = FieldMappingBuilder::OneToOne { index, passthrough }
             },
-            FieldMappingBuilder::NoIndex => return false,
+            // Serde attributes for composite fields go on the individual raw indices
+            FieldMappingBuilder::Composite { .. } | FieldMappingBuilder::NoIndex | FieldMappingBuilder::UnknownFields => return false,
+        }
+        true
+    }
+
+    fn with_conversion(&mut self, conversion: Conversion) -> bool {
+        match std::mem::take(self) {
+            FieldMappingBuilder::Initial => {
+                *self = FieldMappingBuilder::Composite {
+                    conversion: Some(conversion),
+                    raws: Vec::new(),
+                }
+            },
+            FieldMappingBuilder::Composite { conversion: None, raws } => {
+                *self = FieldMappingBuilder::Composite {
+                    conversion: Some(conversion),
+                    raws,
+                }
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    fn with_raw(&mut self, raw: RawIndex) -> bool {
+        match std::mem::take(self) {
+            FieldMappingBuilder::Initial => {
+                *self = FieldMappingBuilder::Composite {
+                    conversion: None,
+                    raws: vec![raw],
+                }
+            },
+            FieldMappingBuilder::Composite { conversion, mut raws } => {
+                raws.push(raw);
+                *self = FieldMappingBuilder::Composite { conversion, raws }
+            },
+            _ => return false,
         }
         true
     }
@@ -231,6 +512,9 @@ impl TryFrom<Field> for FieldMapping {
                 DashAttribute::Index(idx) => builder.with_index(idx),
                 DashAttribute::PassthroughToSerde(tokens) => builder.with_passthrough(tokens),
                 DashAttribute::NoIndex => builder.no_index(),
+                DashAttribute::UnknownFields => builder.unknown_fields(),
+                DashAttribute::Composite(conversion) => builder.with_conversion(conversion),
+                DashAttribute::Raw(raw) => builder.with_raw(raw),
             };
 
             if !build_success {
@@ -252,7 +536,31 @@ impl TryFrom<Field> for FieldMapping {
                 passthrough,
             })),
             FieldMappingBuilder::OneToOne { index: None, .. } => Err(Error::new_spanned(field, "missing #[dash(index = ...)] attribute")),
+            FieldMappingBuilder::Composite { conversion: None, .. } => {
+                Err(Error::new_spanned(field, "missing #[dash(composite(...))] attribute"))
+            },
+            FieldMappingBuilder::Composite {
+                conversion: Some(Conversion { from, fallible, into }),
+                raws,
+            } => {
+                if !raws.iter().any(|raw| matches!(raw, RawIndex::Owned { .. })) {
+                    return Err(Error::new_spanned(
+                        field,
+                        "composite fields need at least one #[dash(raw(...))] attribute",
+                    ));
+                }
+
+                Ok(FieldMapping::Composite(Composite {
+                    field,
+                    api_type,
+                    raws,
+                    from,
+                    fallible,
+                    into,
+                }))
+            },
             FieldMappingBuilder::NoIndex => Ok(FieldMapping::NoIndex { field }),
+            FieldMappingBuilder::UnknownFields => Ok(FieldMapping::UnknownFields { field }),
         }
     }
 }
@@ -260,11 +568,89 @@ impl TryFrom<Field> for FieldMapping {
 enum DashAttribute {
     Index(LitIndex),
     NoIndex,
+    UnknownFields,
+    Composite(Conversion),
+    Raw(RawIndex),
     PassthroughToSerde(TokenStream),
 }
 
+/// Parses `key = "path"`, returning both the key and the parsed path
+fn parse_path_argument(input: ParseStream) -> Result<(Ident, Path)> {
+    let key = input.parse::<Ident>()?;
+    let _ = input.parse::<Token![=]>()?;
+    let path = input.parse::<LitStr>()?.parse()?;
+
+    Ok((key, path))
+}
+
+impl Parse for Conversion {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut from = None;
+        let mut into = None;
+
+        while !input.is_empty() {
+            let (key, path) = parse_path_argument(input)?;
+
+            let previous = if key == "from" {
+                from.replace((path, false))
+            } else if key == "try_from" {
+                from.replace((path, true))
+            } else if key == "into" {
+                into.replace(path).map(|path| (path, false))
+            } else {
+                return Err(Error::new(key.span(), "expected one of `from`, `try_from` or `into`"));
+            };
+
+            if previous.is_some() {
+                return Err(Error::new(key.span(), "duplicate conversion function"));
+            }
+
+            if !input.is_empty() {
+                let _ = input.parse::<Token![,]>()?;
+            }
+        }
+
+        match (from, into) {
+            (Some((from, fallible)), Some(into)) => Ok(Conversion { from, fallible, into }),
+            _ => Err(input.error("expected both `from` (or `try_from`) and `into`")),
+        }
+    }
+}
+
+impl Parse for RawIndex {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.parse::<Ident>()?;
+
+        if key != "index" {
+            return Err(Error::new(key.span(), "expected `index = ...`"));
+        }
+
+        let _ = input.parse::<Token![=]>()?;
+        let index = input.parse()?;
+        let _ = input.parse::<Token![,]>()?;
+
+        let key = input.parse::<Ident>()?;
+
+        if key != "ty" {
+            return Err(Error::new(key.span(), "expected `ty = ...`"));
+        }
+
+        let _ = input.parse::<Token![=]>()?;
+        let ty = Box::new(input.parse()?);
+
+        // Everything after the type is passed through to serde
+        let passthrough = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            vec![input.parse()?]
+        } else {
+            Vec::new()
+        };
+
+        Ok(RawIndex::Owned { index, ty, passthrough })
+    }
+}
+
 impl Parse for DashAttribute {
-    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+    fn parse(input: ParseStream) -> Result<Self> {
         let fork = input.fork();
 
         if let Ok(key) = fork.parse::<Ident>() {
@@ -273,21 +659,40 @@ impl Parse for DashAttribute {
 
                 return Ok(DashAttribute::NoIndex);
             }
+            if key == "unknown_fields" {
+                input.advance_to(&fork);
+
+                return Ok(DashAttribute::UnknownFields);
+            }
             if key == "index" {
                 let _ = fork.parse::<Token![=]>()?;
-                let lookahead = fork.lookahead1();
+                let lit = fork.parse()?;
+
+                input.advance_to(&fork);
+
+                return Ok(DashAttribute::Index(lit));
+            }
+            if key == "depends_on" {
+                let _ = fork.parse::<Token![=]>()?;
+                let lit = fork.parse()?;
+
+                input.advance_to(&fork);
 
-                let lit = if lookahead.peek(LitInt) {
-                    LitIndex::Int(fork.parse()?)
-                } else if lookahead.peek(LitStr) {
-                    LitIndex::Str(fork.parse()?)
+                return Ok(DashAttribute::Raw(RawIndex::Dependency(lit)));
+            }
+            if key == "composite" || key == "raw" {
+                let content;
+                parenthesized!(content in fork);
+
+                let attribute = if key == "composite" {
+                    DashAttribute::Composite(content.parse()?)
                 } else {
-                    return Err(lookahead.error());
+                    DashAttribute::Raw(content.parse()?)
                 };
 
                 input.advance_to(&fork);
 
-                return Ok(DashAttribute::Index(lit));
+                return Ok(attribute);
             }
         }
 
@@ -1,32 +1,97 @@
-use std::convert::TryFrom;
+use std::{collections::HashMap, convert::TryFrom};
 
+use enum_gen::InternalEnum;
 use field::FieldMapping;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use struct_gen::InternalStruct;
-use syn::{parse_macro_input, spanned::Spanned, Data, DataStruct, DeriveInput, Error, Fields, Result};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DataStruct, DeriveInput, Error, Fields, GenericParam, Generics, Ident, Meta, MetaList, Result, Token, Type,
+};
 
+mod enum_gen;
 mod field;
+mod owned_gen;
 mod struct_gen;
 mod utils;
 
 #[proc_macro_derive(Dash, attributes(dash))]
 pub fn derive_dash(ts: TokenStream) -> TokenStream {
     let input = parse_macro_input!(ts as DeriveInput);
-    expand_dash_derive(input)
-        .map(|is| is.to_token_stream())
+    expand_dash_derive(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+#[proc_macro_derive(IntoOwned)]
+pub fn derive_into_owned(ts: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(ts as DeriveInput);
+    owned_gen::expand_into_owned(input)
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
-fn expand_dash_derive(input: DeriveInput) -> Result<InternalStruct> {
-    let DeriveInput { ident, generics, data, .. } = input;
+fn expand_dash_derive(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
 
-    let Data::Struct(DataStruct { fields, .. }) = data else {
-        return Err(Error::new(Span::call_site(), "#[derive(Dash)] only support structs"));
-    };
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => expand_struct(attrs, ident, generics, fields).map(|structs| quote! {#(#structs)*}),
+        Data::Enum(data) => InternalEnum::new(ident, data).map(|ie| ie.to_token_stream()),
+        Data::Union(_) => Err(Error::new(Span::call_site(), "#[derive(Dash)] only support structs and enums")),
+    }
+}
+
+/// A `#[dash(instance(Param = Type, ...))]` attribute, requesting a `Dash` implementation for the
+/// struct with its type parameters replaced by the given types. Parameters not mentioned fall back to
+/// their default.
+struct Instance(Vec<(Ident, Type)>);
+
+impl Parse for Instance {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.parse::<Ident>()?;
+
+        if key != "instance" {
+            return Err(Error::new(key.span(), "expected `instance` or `instance(...)`"));
+        }
+
+        if input.is_empty() {
+            return Ok(Instance(Vec::new()));
+        }
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let substitutions = Punctuated::<(Ident, Type), Token![,]>::parse_terminated_with(&content, |input| {
+            let param = input.parse()?;
+            let _ = input.parse::<Token![=]>()?;
+
+            Ok((param, input.parse()?))
+        })?;
+
+        Ok(Instance(substitutions.into_iter().collect()))
+    }
+}
+
+fn parse_instances(attrs: Vec<Attribute>) -> Result<Vec<Instance>> {
+    attrs
+        .into_iter()
+        .filter_map(|attr| match attr.meta {
+            Meta::List(MetaList { path, tokens, .. }) if path.is_ident("dash") => Some(syn::parse2(tokens)),
+            _ => None,
+        })
+        .collect()
+}
 
+fn expand_struct(attrs: Vec<Attribute>, ident: Ident, generics: Generics, fields: Fields) -> Result<Vec<InternalStruct>> {
     let Fields::Named(fields_named) = fields else {
         return Err(Error::new(fields.span(), "#[derive(Dash) only supports structs with named fields"));
     };
@@ -50,10 +115,115 @@ fn expand_dash_derive(input: DeriveInput) -> Result<InternalStruct> {
             },
         })?;
 
-    Ok(InternalStruct {
-        name: ident,
-        fields,
-        generics,
-        lifetime: primary_lifetime,
-    })
+    if fields.iter().filter(|field| field.is_unknown_fields()).count() > 1 {
+        return Err(Error::new(
+            Span::call_site(),
+            "at most one field can be marked #[dash(unknown_fields)]",
+        ));
+    }
+
+    if primary_lifetime.is_none() && fields.iter().any(FieldMapping::is_unknown_fields) {
+        return Err(Error::new(
+            Span::call_site(),
+            "#[dash(unknown_fields)] requires the struct to have a lifetime",
+        ));
+    }
+
+    let mut mapped_indices = HashMap::new();
+
+    for (index, is_dependency) in fields.iter().flat_map(FieldMapping::indices) {
+        if !is_dependency && mapped_indices.insert(index.value(), index).is_some() {
+            return Err(Error::new(index.span(), "index is mapped by multiple fields"));
+        }
+    }
+
+    for (index, _) in fields
+        .iter()
+        .flat_map(FieldMapping::indices)
+        .filter(|(_, is_dependency)| *is_dependency)
+    {
+        if !mapped_indices.contains_key(&index.value()) {
+            return Err(Error::new(
+                index.span(),
+                "#[dash(depends_on = ...)] must refer to an index mapped by another field",
+            ));
+        }
+    }
+
+    let instances = parse_instances(attrs)?;
+
+    if generics.type_params().next().is_none() {
+        if !instances.is_empty() {
+            return Err(Error::new(
+                Span::call_site(),
+                "#[dash(instance(...))] is only allowed on structs with type parameters",
+            ));
+        }
+
+        return Ok(vec![InternalStruct {
+            name: ident,
+            fields,
+            generics,
+            lifetime: primary_lifetime,
+            type_args: Vec::new(),
+        }]);
+    }
+
+    if instances.is_empty() {
+        return Err(Error::new(
+            generics.span(),
+            "structs with type parameters need at least one #[dash(instance(...))] attribute",
+        ));
+    }
+
+    // The internal structs of each instance only retain the struct's lifetime
+    let mut instance_generics = generics.clone();
+    instance_generics.params = generics
+        .params
+        .iter()
+        .filter(|param| matches!(param, GenericParam::Lifetime(_)))
+        .cloned()
+        .collect();
+
+    instances
+        .into_iter()
+        .map(|Instance(explicit)| {
+            for (param, _) in &explicit {
+                if !generics.type_params().any(|type_param| type_param.ident == *param) {
+                    return Err(Error::new(param.span(), "unknown type parameter"));
+                }
+            }
+
+            let substitutions = generics
+                .type_params()
+                .map(|type_param| {
+                    let ty = match explicit.iter().find(|(param, _)| *param == type_param.ident) {
+                        Some((_, ty)) => ty.clone(),
+                        None => type_param.default.clone().ok_or_else(|| {
+                            Error::new(
+                                type_param.span(),
+                                "type parameter has no default and is missing from #[dash(instance(...))]",
+                            )
+                        })?,
+                    };
+
+                    Ok((type_param.ident.clone(), ty))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let mut fields = fields.clone();
+
+            for field in &mut fields {
+                field.substitute_types(&substitutions);
+            }
+
+            Ok(InternalStruct {
+                name: ident.clone(),
+                fields,
+                generics: instance_generics.clone(),
+                lifetime: primary_lifetime.clone(),
+                type_args: substitutions.into_iter().map(|(_, ty)| ty).collect(),
+            })
+        })
+        .collect()
 }
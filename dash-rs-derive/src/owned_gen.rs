@@ -0,0 +1,109 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{parse_quote, visit::Visit, Data, DataStruct, DeriveInput, Error, Fields, GenericParam, Lifetime, Result, Type, TypePath};
+
+use crate::utils;
+
+/// Visitor checking whether a type mentions some given lifetime or type parameters
+struct Borrows<'a> {
+    lifetime: Option<&'a Lifetime>,
+    type_params: &'a [Ident],
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for Borrows<'_> {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.found |= self.lifetime == Some(lifetime);
+    }
+
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        if let Some(ident) = type_path.path.get_ident() {
+            self.found |= type_path.qself.is_none() && self.type_params.contains(ident);
+        }
+
+        syn::visit::visit_type_path(self, type_path)
+    }
+}
+
+/// Whether values of the given type need to be converted when detaching the struct from its input,
+/// or whether they can simply be moved
+fn needs_conversion(ty: &Type, lifetime: Option<&Lifetime>, type_params: &[Ident]) -> bool {
+    let mut visitor = Borrows {
+        lifetime,
+        type_params,
+        found: false,
+    };
+
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+pub fn expand_into_owned(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput { ident, generics, data, .. } = input;
+
+    let Data::Struct(DataStruct {
+        fields: Fields::Named(fields_named),
+        ..
+    }) = data
+    else {
+        return Err(Error::new(
+            ident.span(),
+            "#[derive(IntoOwned)] only supports structs with named fields",
+        ));
+    };
+
+    if generics.params.is_empty() {
+        return Err(Error::new(
+            ident.span(),
+            "#[derive(IntoOwned)] requires a lifetime or type parameter, use into_owned_identity! instead",
+        ));
+    }
+
+    let lifetime = utils::find_unique_lifetime(&generics)?.map(|param| param.lifetime);
+    let type_params = generics.type_params().map(|param| param.ident.clone()).collect::<Vec<_>>();
+
+    let owned_args = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote! {'static},
+        GenericParam::Type(param) => {
+            let ident = &param.ident;
+
+            quote! {<#ident as crate::serde::IntoOwned>::Owned}
+        },
+        GenericParam::Const(param) => {
+            let ident = &param.ident;
+
+            quote! {#ident}
+        },
+    });
+
+    let conversions = fields_named.named.iter().map(|field| {
+        let name = field.ident.as_ref().unwrap();
+
+        if needs_conversion(&field.ty, lifetime.as_ref(), &type_params) {
+            quote! {#name: crate::serde::IntoOwned::into_owned(self.#name),}
+        } else {
+            quote! {#name: self.#name,}
+        }
+    });
+
+    let mut bounded_generics = generics.clone();
+    let where_clause = bounded_generics.make_where_clause();
+
+    for param in &type_params {
+        where_clause.predicates.push(parse_quote! {#param: crate::serde::IntoOwned});
+    }
+
+    let (impl_generics, type_generics, where_clause) = bounded_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics crate::serde::IntoOwned for #ident #type_generics #where_clause {
+            type Owned = #ident<#(#owned_args),*>;
+
+            fn into_owned(self) -> Self::Owned {
+                #ident {
+                    #(#conversions)*
+                }
+            }
+        }
+    })
+}
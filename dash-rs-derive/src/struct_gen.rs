@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, ToTokens};
-use syn::{Generics, Lifetime, LifetimeParam};
+use syn::{Generics, Lifetime, LifetimeParam, Type};
 
 use crate::field::FieldMapping;
 
@@ -11,6 +11,9 @@ pub struct InternalStruct {
     pub generics: Generics,
     /// The unique lifetime of the struct for which we are deriving `Dash`, if it exists.
     pub lifetime: Option<LifetimeParam>,
+    /// The concrete types substituted for the struct's type parameters, in declaration order. In
+    /// this case, [`InternalStruct::generics`] only contains the struct's lifetime.
+    pub type_args: Vec<Type>,
 }
 
 impl InternalStruct {
@@ -42,7 +45,12 @@ impl InternalStruct {
 
     fn de_struct(&self) -> proc_macro2::TokenStream {
         let name = self.deserialize_struct_name();
-        let fields = self.fields.iter().map(|ifield| ifield.de_field_tokens());
+        let static_lifetime = Lifetime::new("'static", Span::call_site());
+        let lifetime = match self.lifetime {
+            Some(ref lifetime) => &lifetime.lifetime,
+            None => &static_lifetime,
+        };
+        let fields = self.fields.iter().map(|ifield| ifield.de_field_tokens(lifetime));
         let generics = &self.generics;
 
         quote! {
@@ -56,9 +64,11 @@ impl InternalStruct {
     fn serialize_implementation(&self) -> proc_macro2::TokenStream {
         // assume a `Serializer` is in scope, named serializer
         let serialize_struct = self.serialize_struct_name();
+        let preludes = self.fields.iter().map(|ifield| ifield.serialize_prelude());
         let initializers = self.fields.iter().map(|ifield| ifield.serialize());
 
         quote! {
+            #(#preludes)*
             let internal = #serialize_struct {
                 #(#initializers)*
             };
@@ -72,12 +82,23 @@ impl InternalStruct {
         let api_struct = &self.name;
         let initializers = self.fields.iter().map(|ifield| ifield.deserialize());
 
-        quote! {
-            let internal = #deserialize_struct::deserialize(deserializer)?;
+        if self.fields.iter().any(FieldMapping::is_unknown_fields) {
+            quote! {
+                let mut __unknown_fields = crate::serde::UnknownFields::new();
+                let internal = #deserialize_struct::deserialize(crate::serde::UnknownFieldsCollector::new(deserializer, &mut __unknown_fields))?;
 
-            Ok(#api_struct {
-                #(#initializers)*
-            })
+                Ok(#api_struct {
+                    #(#initializers)*
+                })
+            }
+        } else {
+            quote! {
+                let internal = #deserialize_struct::deserialize(deserializer)?;
+
+                Ok(#api_struct {
+                    #(#initializers)*
+                })
+            }
         }
     }
 }
@@ -94,6 +115,9 @@ impl ToTokens for InternalStruct {
             Some(ref lifetime) => (quote! {<#existing_params>}, &lifetime.lifetime),
             None => (quote! {<#artificial_lifetime,#existing_params>}, &artificial_lifetime),
         };
+        let self_lifetimes = self.generics.lifetimes().map(|param| &param.lifetime);
+        let type_args = &self.type_args;
+        let self_type = quote! {#name<#(#self_lifetimes,)* #(#type_args,)*>};
         let where_clause = &self.generics.where_clause;
 
         let deserialize_impl = self.deserialize_implementation();
@@ -108,7 +132,7 @@ impl ToTokens for InternalStruct {
                 #ser_struct
                 #de_struct
 
-                impl#generic_arg_list Dash<#lifetime> for #name<#existing_params>
+                impl#generic_arg_list Dash<#lifetime> for #self_type
                     #where_clause
                 {
                     fn dash_deserialize<D: Deserializer<#lifetime>>(deserializer: D) -> Result<Self, D::Error> {
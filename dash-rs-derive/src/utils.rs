@@ -1,4 +1,9 @@
-use syn::{spanned::Spanned, Error, Generics, LifetimeParam, Result, Type};
+use proc_macro2::Ident;
+use syn::{
+    spanned::Spanned,
+    visit_mut::{self, VisitMut},
+    Error, Generics, LifetimeParam, Result, Type,
+};
 
 /// If the given [`Generics`] contain a unique lifetime, return it. If there are no lifetimes,
 /// return a `'static` lifetime. Otherwise, return a spanned error indicating either a lack of
@@ -54,3 +59,28 @@ pub fn type_contains_lifetime(ty: &Type) -> bool {
         _ => todo!(),
     }
 }
+
+/// Replaces every occurrence of the given type parameters in `ty` with their substitutions
+pub fn substitute_types(ty: &mut Type, substitutions: &[(Ident, Type)]) {
+    struct Substitute<'a>(&'a [(Ident, Type)]);
+
+    impl VisitMut for Substitute<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::Path(type_path) = ty {
+                if type_path.qself.is_none() {
+                    if let Some(ident) = type_path.path.get_ident() {
+                        if let Some((_, substitution)) = self.0.iter().find(|(param, _)| param == ident) {
+                            *ty = substitution.clone();
+
+                            return;
+                        }
+                    }
+                }
+            }
+
+            visit_mut::visit_type_mut(self, ty)
+        }
+    }
+
+    Substitute(substitutions).visit_type_mut(ty)
+}
@@ -3,7 +3,11 @@
 pub mod model;
 pub mod request;
 pub mod response;
+pub mod savefile;
 pub(crate) mod serde;
 pub mod util;
 
-pub use crate::serde::{Dash, DeError, GJFormat, IndexedDeserializer, IndexedSerializer, ProcessError, SerError, Thunk, ThunkProcessor};
+pub use crate::serde::{
+    Dash, DeError, GJFormat, IndexedDeserializer, IndexedSerializer, IntoOwned, PlistDeserializer, PlistFormat, PlistSerializer,
+    ProcessError, SerError, Thunk, ThunkProcessor, UnknownFields,
+};
@@ -1,17 +1,20 @@
 use std::borrow::Cow;
 // use std::borrow::Cow;
 
-use dash_rs_derive::Dash;
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
 use variant_partial_eq::VariantPartialEq;
 
 use crate::{
-    model::user::{Color, IconType, ModLevel},
+    model::{
+        time::RelativeTime,
+        user::{Color, IconType, ModLevel},
+    },
     serde::{Base64Decoder, Thunk},
     GJFormat, ProcessError, ThunkProcessor,
 };
 
-#[derive(Debug, Eq, VariantPartialEq, Clone, Deserialize, Serialize, Dash)]
+#[derive(Debug, Eq, VariantPartialEq, Clone, Deserialize, Serialize, Dash, IntoOwned)]
 pub struct LevelComment<'a> {
     /// Information about the user that made this [`LevelComment`]. Is generally a [`CommentUser`]
     /// object
@@ -44,7 +47,7 @@ pub struct LevelComment<'a> {
 
     /// Robtop's completely braindead way of keeping track of when this [`LevelComment`] was posted
     #[dash(index = 9)]
-    pub time_since_post: Cow<'a, str>,
+    pub time_since_post: Thunk<'a, RelativeTime>,
 
     /// If enabled by the user making this [`LevelComment`], the progress they have done on the
     /// level this comment is on.
@@ -69,6 +72,8 @@ impl<'de> GJFormat<'de> for LevelComment<'de> {
     const MAP_LIKE: bool = true;
 }
 
+crate::into_owned_identity!(Color);
+
 impl ThunkProcessor for Color {
     type Error = ProcessError;
     type Output<'a> = Color;
@@ -102,7 +107,7 @@ impl ThunkProcessor for Color {
     }
 }
 
-#[derive(Debug, Eq, VariantPartialEq, Clone, Deserialize, Serialize, Dash)]
+#[derive(Debug, Eq, VariantPartialEq, Clone, Deserialize, Serialize, Dash, IntoOwned)]
 pub struct CommentUser<'a> {
     /// This [`CommentUser`]'s name
     #[dash(index = 1)]
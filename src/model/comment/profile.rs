@@ -1,13 +1,13 @@
 use crate::{
+    model::time::RelativeTime,
     serde::{Base64Decoder, Thunk},
     GJFormat,
 };
-use dash_rs_derive::Dash;
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
 use variant_partial_eq::VariantPartialEq;
 
-#[derive(Debug, Serialize, Deserialize, Eq, VariantPartialEq, Clone, Dash)]
+#[derive(Debug, Serialize, Deserialize, Eq, VariantPartialEq, Clone, Dash, IntoOwned)]
 pub struct ProfileComment<'a> {
     /// The actual content of the [`ProfileComment`] made.
     #[serde(borrow)]
@@ -27,7 +27,7 @@ pub struct ProfileComment<'a> {
     /// Robtop's completely braindead way of keeping track of when this [`ProfileComment`] was
     /// posted
     #[dash(index = 9)]
-    pub time_since_post: Cow<'a, str>,
+    pub time_since_post: Thunk<'a, RelativeTime>,
 }
 
 impl<'de> GJFormat<'de> for ProfileComment<'de> {
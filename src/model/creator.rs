@@ -1,4 +1,4 @@
-use dash_rs_derive::Dash;
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use variant_partial_eq::VariantPartialEq;
@@ -13,7 +13,7 @@ use crate::GJFormat;
 ///
 /// Creators do not use the map-like representation, meaning the order of fields in the raw data
 /// must correspond to the order of fields in this struct.
-#[derive(Debug, Deserialize, Serialize, VariantPartialEq, Eq, Clone, Dash)]
+#[derive(Debug, Deserialize, Serialize, VariantPartialEq, Eq, Clone, Dash, IntoOwned)]
 pub struct Creator<'a> {
     /// The [`Creator`]'s unique user ID
     #[dash(index = 1)]
@@ -36,13 +36,3 @@ impl<'de> GJFormat<'de> for Creator<'de> {
     const DELIMITER: &'static str = ":";
     const MAP_LIKE: bool = false;
 }
-
-impl<'a> Creator<'a> {
-    pub fn into_owned(self) -> Creator<'static> {
-        Creator {
-            user_id: self.user_id,
-            name: Cow::Owned(self.name.into_owned()),
-            account_id: self.account_id,
-        }
-    }
-}
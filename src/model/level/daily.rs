@@ -0,0 +1,38 @@
+//! Module containing the model for the information the servers provide about the current daily,
+//! weekly and event levels
+
+use dash_rs_derive::Dash;
+use serde::{Deserialize, Serialize};
+
+use crate::GJFormat;
+
+/// Information about the current daily, weekly or event level
+///
+/// These can be retrieved using a [`DailyLevelRequest`](crate::request::level::DailyLevelRequest).
+/// Note that this does not contain the level itself, which instead has to be downloaded using
+/// [`LevelRequest::daily`](crate::request::level::LevelRequest::daily).
+///
+/// ## GD Internals:
+/// The Geometry Dash servers provide this data via the `getGJDailyLevel` endpoint.
+///
+/// This data does not use the map-like representation, meaning the order of fields in the raw data
+/// must correspond to the order of fields in this struct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Dash)]
+pub struct DailyLevel {
+    /// The index of the current level, i.e. how many levels of this type there have been so far.
+    ///
+    /// ## GD Internals:
+    /// For weekly demons, this value is offset by `100000`, so that the indices of daily levels and
+    /// weekly demons never overlap.
+    #[dash(index = 1)]
+    pub index: u32,
+
+    /// The amount of seconds until the current level is replaced
+    #[dash(index = 2)]
+    pub seconds_until_reset: u64,
+}
+
+impl<'de> GJFormat<'de> for DailyLevel {
+    const DELIMITER: &'static str = "|";
+    const MAP_LIKE: bool = false;
+}
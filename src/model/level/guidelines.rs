@@ -0,0 +1,243 @@
+//! Module containing types modelling the song guidelines that can be placed in the level editor
+
+use crate::{
+    model::level::timing::LevelTiming,
+    serde::{ProcessError, ThunkProcessor},
+};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt::Write, num::ParseFloatError, slice, str::FromStr};
+
+/// Enum modelling the colors a song guideline can have
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GuidelineColor {
+    Orange,
+    Yellow,
+    Green,
+
+    /// Some color value not known to dash-rs. The game displays these as orange guidelines.
+    Unknown(f32),
+}
+
+impl FromStr for GuidelineColor {
+    type Err = ParseFloatError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        // Match on the token itself instead of comparing the parsed value, as a float parsed from
+        // "0.8" is not guaranteed to compare equal to the literal `0.8`
+        Ok(match token {
+            "0.8" => GuidelineColor::Orange,
+            "0.9" => GuidelineColor::Yellow,
+            "1" | "1.0" => GuidelineColor::Green,
+            _ => GuidelineColor::Unknown(token.parse()?),
+        })
+    }
+}
+
+impl From<GuidelineColor> for f32 {
+    fn from(color: GuidelineColor) -> Self {
+        match color {
+            GuidelineColor::Orange => 0.8,
+            GuidelineColor::Yellow => 0.9,
+            GuidelineColor::Green => 1.0,
+            GuidelineColor::Unknown(value) => value,
+        }
+    }
+}
+
+/// A single song guideline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Guideline {
+    /// The point in time (in seconds since the level started) at which this guideline is placed
+    pub time: f32,
+
+    /// The color of this guideline
+    pub color: GuidelineColor,
+}
+
+/// The song guidelines of a level, ordered the way they were placed in the editor
+///
+/// ## GD Internals:
+/// Guidelines are stored at index `kA14` of the level's metadata section, as a `~`-separated list
+/// of alternating timestamps and color values (`0.8` for orange, `0.9` for yellow and `1.0` for green).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Guidelines(pub Vec<Guideline>);
+
+impl Guidelines {
+    /// The number of guidelines
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no guidelines
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the guidelines in the order they were placed
+    pub fn iter(&self) -> slice::Iter<'_, Guideline> {
+        self.0.iter()
+    }
+
+    /// Iterates mutably over the guidelines in the order they were placed, allowing their time and
+    /// color to be edited in place
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Guideline> {
+        self.0.iter_mut()
+    }
+
+    /// Places a new guideline after all existing ones
+    pub fn push(&mut self, guideline: Guideline) {
+        self.0.push(guideline)
+    }
+
+    /// Places a new guideline at the given position in the list of guidelines, shifting all
+    /// guidelines after it to the right
+    ///
+    /// ## Panics
+    /// Panics if `index > self.len()`
+    pub fn insert(&mut self, index: usize, guideline: Guideline) {
+        self.0.insert(index, guideline)
+    }
+
+    /// Removes and returns the guideline at the given position in the list of guidelines
+    ///
+    /// ## Panics
+    /// Panics if `index >= self.len()`
+    pub fn remove(&mut self, index: usize) -> Guideline {
+        self.0.remove(index)
+    }
+
+    /// Removes all guidelines placed at exactly the given time, returning how many were removed
+    pub fn remove_at(&mut self, time: f32) -> usize {
+        let len = self.0.len();
+        self.0.retain(|guideline| guideline.time != time);
+        len - self.0.len()
+    }
+
+    /// Calculates the x position of each guideline using the given timing, which should be the
+    /// timing of the level when played from the beginning (see [`Objects::timing`]).
+    ///
+    /// The returned vector contains the positions in the same order as the guidelines.
+    ///
+    /// [`Objects::timing`]: crate::model::level::Objects::timing
+    pub fn x_positions(&self, timing: &LevelTiming) -> Vec<f32> {
+        self.0.iter().map(|guideline| timing.x_at(guideline.time)).collect()
+    }
+}
+
+crate::into_owned_identity!(Guidelines);
+
+impl ThunkProcessor for Guidelines {
+    type Error = ProcessError;
+    type Output<'a> = Guidelines;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        let values = unprocessed.split_terminator('~').collect::<Vec<_>>();
+
+        if values.len() % 2 != 0 {
+            return Err(ProcessError::OddLength(values.len()));
+        }
+
+        values
+            .chunks(2)
+            .map(|pair| {
+                Ok(Guideline {
+                    time: pair[0].parse()?,
+                    color: pair[1].parse()?,
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Guidelines)
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        let mut unprocessed = String::new();
+
+        for guideline in &processed.0 {
+            // Writing to a String cannot fail
+            let _ = write!(unprocessed, "{}~{}~", guideline.time, f32::from(guideline.color));
+        }
+
+        Ok(Cow::Owned(unprocessed))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::guidelines::{Guideline, GuidelineColor, Guidelines},
+        serde::ProcessError,
+        ThunkProcessor,
+    };
+    use std::borrow::Cow;
+
+    #[test]
+    fn deserialize_guidelines() {
+        let guidelines = Guidelines::from_unprocessed(Cow::Borrowed("0.5~0.8~1.25~0.9~2~1~")).unwrap();
+
+        assert_eq!(
+            guidelines.0,
+            vec![
+                Guideline {
+                    time: 0.5,
+                    color: GuidelineColor::Orange
+                },
+                Guideline {
+                    time: 1.25,
+                    color: GuidelineColor::Yellow
+                },
+                Guideline {
+                    time: 2.0,
+                    color: GuidelineColor::Green
+                },
+            ]
+        );
+        assert_eq!(Guidelines::as_unprocessed(&guidelines).unwrap(), "0.5~0.8~1.25~0.9~2~1~");
+    }
+
+    #[test]
+    fn deserialize_empty_guidelines() {
+        assert_eq!(Guidelines::from_unprocessed(Cow::Borrowed("")).unwrap(), Guidelines::default());
+        assert!(matches!(
+            Guidelines::from_unprocessed(Cow::Borrowed("0.5~0.8~1.25~")),
+            Err(ProcessError::OddLength(3))
+        ));
+    }
+
+    #[test]
+    fn deserialize_unknown_guideline_color() {
+        let guidelines = Guidelines::from_unprocessed(Cow::Borrowed("1~0.80~2~0.5~")).unwrap();
+
+        assert_eq!(guidelines.0[0].color, GuidelineColor::Unknown(0.8));
+        assert_eq!(guidelines.0[1].color, GuidelineColor::Unknown(0.5));
+    }
+
+    #[test]
+    fn edit_guidelines() {
+        let mut guidelines = Guidelines::from_unprocessed(Cow::Borrowed("0.5~0.8~1.25~0.9~")).unwrap();
+
+        guidelines.push(Guideline {
+            time: 2.0,
+            color: GuidelineColor::Green,
+        });
+        guidelines.insert(
+            0,
+            Guideline {
+                time: 0.25,
+                color: GuidelineColor::Yellow,
+            },
+        );
+        assert_eq!(guidelines.remove(2).time, 1.25);
+
+        for guideline in guidelines.iter_mut() {
+            guideline.time *= 2.0;
+        }
+
+        assert_eq!(guidelines.remove_at(1.0), 1);
+        assert_eq!(guidelines.len(), 2);
+        assert_eq!(Guidelines::as_unprocessed(&guidelines).unwrap(), "0.5~0.9~4~1~");
+    }
+}
@@ -0,0 +1,118 @@
+//! Module containing an index over the objects of a level, allowing constant time lookups of
+//! objects by group and color channel
+
+use crate::model::level::{object::LevelObject, Objects};
+use std::collections::HashMap;
+
+/// Index over the objects of a level, answering group and color channel related queries without
+/// scanning the whole level.
+///
+/// Construct it via [`Objects::index`]. All queries return the matching objects in the order they
+/// appear in the level.
+#[derive(Debug, Clone, Default)]
+pub struct ObjectIndex<'a> {
+    by_group: HashMap<u16, Vec<&'a LevelObject<'static>>>,
+    by_target_group: HashMap<u16, Vec<&'a LevelObject<'static>>>,
+    by_color_channel: HashMap<u16, Vec<&'a LevelObject<'static>>>,
+    by_target_color_channel: HashMap<u16, Vec<&'a LevelObject<'static>>>,
+}
+
+impl<'a> ObjectIndex<'a> {
+    /// Builds the index for the given level
+    pub fn new(objects: &'a Objects) -> ObjectIndex<'a> {
+        let mut index = ObjectIndex::default();
+
+        for object in &objects.objects {
+            for &group in &object.groups {
+                index.by_group.entry(group).or_default().push(object);
+            }
+
+            if let Some(group) = object.metadata.target_group() {
+                index.by_target_group.entry(group).or_default().push(object);
+            }
+
+            if let Some(channel) = object.main_color_channel {
+                index.by_color_channel.entry(channel).or_default().push(object);
+            }
+
+            // Don't list objects twice if both their colors use the same channel
+            if let Some(channel) = object
+                .detail_color_channel
+                .filter(|&channel| Some(channel) != object.main_color_channel)
+            {
+                index.by_color_channel.entry(channel).or_default().push(object);
+            }
+
+            if let Some(channel) = object.metadata.target_color_channel() {
+                index.by_target_color_channel.entry(channel).or_default().push(object);
+            }
+        }
+
+        index
+    }
+
+    /// All objects that are part of the given group
+    pub fn in_group(&self, group: u16) -> &[&'a LevelObject<'static>] {
+        lookup(&self.by_group, group)
+    }
+
+    /// All triggers acting on the given group
+    pub fn targeting_group(&self, group: u16) -> &[&'a LevelObject<'static>] {
+        lookup(&self.by_target_group, group)
+    }
+
+    /// All objects whose main or detail color is explicitly set to the given color channel.
+    ///
+    /// Objects using their default color channels are not included.
+    pub fn with_color_channel(&self, channel: u16) -> &[&'a LevelObject<'static>] {
+        lookup(&self.by_color_channel, channel)
+    }
+
+    /// All triggers acting on the given color channel
+    pub fn targeting_color_channel(&self, channel: u16) -> &[&'a LevelObject<'static>] {
+        lookup(&self.by_target_color_channel, channel)
+    }
+}
+
+fn lookup<'s, 'a>(map: &'s HashMap<u16, Vec<&'a LevelObject<'static>>>, key: u16) -> &'s [&'a LevelObject<'static>] {
+    map.get(&key).map(Vec::as_slice).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::{metadata::LevelMetadata, object::LevelObject, LevelCompression, Objects},
+        GJFormat, IntoOwned,
+    };
+
+    #[test]
+    fn index_lookups() {
+        let objects = Objects {
+            meta: LevelMetadata::default(),
+            objects: [
+                "1,1,2,15,3,15,21,3,22,3,57,2.3",
+                "1,1,2,45,3,15,21,4,22,3,57,3",
+                "1,901,2,75,3,15,10,0.5,28,30,51,3",
+                "1,1006,2,105,3,15,51,4,52,0",
+                "1,899,2,135,3,15,23,3",
+            ]
+            .iter()
+            .map(|raw| LevelObject::from_gj_str(raw).unwrap().into_owned())
+            .collect(),
+            compression: LevelCompression::default(),
+        };
+        let index = objects.index();
+
+        let xs = |objects: &[&LevelObject]| objects.iter().map(|object| object.x).collect::<Vec<_>>();
+
+        assert_eq!(xs(index.in_group(2)), vec![15.0]);
+        assert_eq!(xs(index.in_group(3)), vec![15.0, 45.0]);
+        assert_eq!(xs(index.in_group(4)), Vec::<f32>::new());
+        assert_eq!(xs(index.targeting_group(3)), vec![75.0]);
+        assert_eq!(xs(index.targeting_group(4)), Vec::<f32>::new());
+        assert_eq!(xs(index.with_color_channel(3)), vec![15.0, 45.0]);
+        assert_eq!(xs(index.with_color_channel(4)), vec![45.0]);
+        assert_eq!(xs(index.targeting_color_channel(3)), vec![135.0]);
+        assert_eq!(xs(index.targeting_color_channel(4)), vec![105.0]);
+    }
+}
@@ -1,353 +1,137 @@
+//! Conversion functions for the fields of [`Level`](super::Level) that are constructed from more
+//! than one index
+//!
+//! These are referenced from the `#[dash(composite(...))]` attributes on [`Level`](super::Level).
+
 use crate::{
     model::{
-        level::{DemonRating, Level, LevelData, LevelLength, LevelRating},
+        level::{DemonRating, LevelData, LevelRating},
         song::MainSong,
     },
     serde::InternalProxy,
-    Dash,
 };
-use serde::{de::Error, Deserialize, Serialize};
-use std::borrow::Borrow;
-
-#[derive(Serialize, Deserialize, Debug)]
-struct InternalLevel<'src> {
-    #[serde(rename = "1")]
-    index_1: u64,
-    #[serde(rename = "2")]
-    index_2: &'src str,
-    #[serde(rename = "3")]
-    index_3: Option<&'src str>,
-    #[serde(rename = "5")]
-    index_5: u32,
-    #[serde(rename = "6")]
-    index_6: u64,
-    #[serde(serialize_with = "crate::util::false_to_empty_string")]
-    #[serde(rename = "25")]
-    index_25: bool,
-    #[serde(serialize_with = "crate::util::true_to_ten")]
-    #[serde(rename = "8")]
-    index_8: bool,
-    #[serde(rename = "9")]
-    index_9: i32,
-    #[serde(serialize_with = "crate::util::false_to_empty_string")]
-    #[serde(rename = "17")]
-    index_17: bool,
-    #[serde(rename = "10")]
-    index_10: u32,
-    #[serde(rename = "12")]
-    index_12: u8,
-    #[serde(rename = "13")]
-    index_13: u8,
-    #[serde(rename = "14")]
-    index_14: i32,
-    #[serde(rename = "15")]
-    index_15: i32,
-    #[serde(rename = "18")]
-    index_18: u8,
-    #[serde(rename = "19")]
-    index_19: i32,
-    #[serde(with = "crate::util::default_to_none")]
-    #[serde(rename = "30")]
-    index_30: Option<u64>,
-    #[serde(rename = "31")]
-    index_31: bool,
-    #[serde(with = "crate::util::default_to_none")]
-    #[serde(rename = "35")]
-    index_35: Option<u64>,
-    #[serde(rename = "37")]
-    index_37: u8,
-    #[serde(rename = "38")]
-    index_38: bool,
-    #[serde(with = "crate::util::default_to_none")]
-    #[serde(rename = "39")]
-    index_39: Option<u8>,
-    #[serde(rename = "42")]
-    index_42: bool,
-    #[serde(rename = "43")]
-    index_43: u8,
-    #[serde(with = "crate::util::default_to_none")]
-    #[serde(rename = "45")]
-    index_45: Option<u32>,
-    #[serde(rename = "46")]
-    index_46: Option<&'src str>,
-    #[serde(rename = "47")]
-    index_47: Option<&'src str>,
-
-    // Only present sometimes
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "4")]
-    index_4: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "27")]
-    index_27: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "28")]
-    index_28: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "29")]
-    index_29: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "36")]
-    index_36: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "40")]
-    index_40: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "52")]
-    index_52: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "53")]
-    index_53: Option<&'src str>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "57")]
-    index_57: Option<&'src str>,
-}
-
-impl<'de> Dash<'de> for Level<'de, (), Option<u64>, u64> {
-    fn dash_deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let internal = InternalLevel::deserialize(deserializer)?;
-
-        Ok(Self {
-            level_id: InternalProxy::from_deserialize_proxy(internal.index_1),
-            name: InternalProxy::from_deserialize_proxy(internal.index_2),
-            description: InternalProxy::from_deserialize_proxy(internal.index_3),
-            version: InternalProxy::from_deserialize_proxy(internal.index_5),
-            creator: InternalProxy::from_deserialize_proxy(internal.index_6),
-            downloads: InternalProxy::from_deserialize_proxy(internal.index_10),
-            gd_version: InternalProxy::from_deserialize_proxy(internal.index_13),
-            likes: InternalProxy::from_deserialize_proxy(internal.index_14),
-            length: InternalProxy::from_deserialize_proxy(internal.index_15),
-            stars: InternalProxy::from_deserialize_proxy(internal.index_18),
-            featured: InternalProxy::from_deserialize_proxy(internal.index_19),
-            copy_of: InternalProxy::from_deserialize_proxy(internal.index_30),
-            two_player: InternalProxy::from_deserialize_proxy(internal.index_31),
-            custom_song: InternalProxy::from_deserialize_proxy(internal.index_35),
-            coin_amount: InternalProxy::from_deserialize_proxy(internal.index_37),
-            coins_verified: InternalProxy::from_deserialize_proxy(internal.index_38),
-            stars_requested: InternalProxy::from_deserialize_proxy(internal.index_39),
-            is_epic: InternalProxy::from_deserialize_proxy(internal.index_42),
-            object_amount: InternalProxy::from_deserialize_proxy(internal.index_45),
-            index_46: InternalProxy::from_deserialize_proxy(internal.index_46),
-            index_47: InternalProxy::from_deserialize_proxy(internal.index_47),
-
-            main_song: if internal.index_35.is_some() {
-                None
-            } else {
-                Some(MainSong::from(internal.index_12))
-            },
-            difficulty: if !internal.index_8 {
-                LevelRating::NotAvailable
-            } else if internal.index_25 {
-                LevelRating::Auto
-            } else if internal.index_17 {
-                LevelRating::Demon(DemonRating::from_response_value(internal.index_9))
-            } else {
-                LevelRating::from_response_value(internal.index_9)
-            },
-            level_data: (),
-        })
+use std::borrow::Cow;
+
+/// Constructs a level's difficulty from the indices `25`, `8`, `9`, `17` and `43`
+pub(super) fn difficulty_from_raw(is_auto: bool, is_rated: bool, rating: i32, is_demon: bool, _demon_rating: u8) -> LevelRating {
+    if !is_rated {
+        LevelRating::NotAvailable
+    } else if is_auto {
+        LevelRating::Auto
+    } else if is_demon {
+        LevelRating::Demon(DemonRating::from_response_value(rating))
+    } else {
+        LevelRating::from_response_value(rating)
     }
+}
 
-    fn dash_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        // We are in a manual impl, so we can do the .as_deref() trick to avoid needing two separate structs
-        let index_3 = self.description.to_serialize_proxy();
-
-        let internal = InternalLevel {
-            index_1: self.level_id.to_serialize_proxy(),
-            index_2: self.name.to_serialize_proxy(),
-            index_3: index_3.as_deref(),
-            index_5: self.version.to_serialize_proxy(),
-            index_6: self.creator.to_serialize_proxy(),
-            index_10: self.downloads.to_serialize_proxy(),
-            index_13: self.gd_version.to_serialize_proxy(),
-            index_14: self.likes.to_serialize_proxy(),
-            index_15: self.length.to_serialize_proxy(),
-            index_18: self.stars.to_serialize_proxy(),
-            index_19: self.featured.to_serialize_proxy(),
-            index_30: self.copy_of.to_serialize_proxy(),
-            index_31: self.two_player.to_serialize_proxy(),
-            index_35: self.custom_song.to_serialize_proxy(),
-            index_37: self.coin_amount.to_serialize_proxy(),
-            index_38: self.coins_verified.to_serialize_proxy(),
-            index_39: self.stars_requested.to_serialize_proxy(),
-            index_42: self.is_epic.to_serialize_proxy(),
-            index_45: self.object_amount.to_serialize_proxy(),
-            index_46: self.index_46.to_serialize_proxy(),
-            index_47: self.index_47.to_serialize_proxy(),
+pub(super) fn difficulty_into_raw(difficulty: &LevelRating) -> (bool, bool, i32, bool, u8) {
+    let demon_rating = match difficulty {
+        LevelRating::Demon(DemonRating::Easy) => 3,
+        LevelRating::Demon(DemonRating::Medium) => 4,
+        LevelRating::Demon(DemonRating::Hard) => 0,
+        LevelRating::Demon(DemonRating::Insane) => 5,
+        LevelRating::Demon(DemonRating::Extreme) => 6,
+        _ => 5,
+    };
+
+    (
+        *difficulty == LevelRating::Auto,
+        *difficulty != LevelRating::NotAvailable,
+        difficulty.into_response_value(),
+        difficulty.is_demon(),
+        demon_rating,
+    )
+}
 
-            index_12: self.main_song.map(|song| song.main_song_id).unwrap_or(0),
-            index_25: self.difficulty == LevelRating::Auto,
-            index_8: self.difficulty != LevelRating::NotAvailable,
-            index_9: self.difficulty.into_response_value(),
-            index_17: self.difficulty.is_demon(),
-            index_43: match self.difficulty {
-                LevelRating::Demon(DemonRating::Easy) => 3,
-                LevelRating::Demon(DemonRating::Medium) => 4,
-                LevelRating::Demon(DemonRating::Hard) => 0,
-                LevelRating::Demon(DemonRating::Insane) => 5,
-                LevelRating::Demon(DemonRating::Extreme) => 6,
-                _ => 5,
-            },
-            index_4: None,
-            index_27: None,
-            index_28: None,
-            index_29: None,
-            index_36: None,
-            index_40: None,
-            index_52: None,
-            index_53: None,
-            index_57: None,
-        };
-        internal.serialize(serializer)
+/// Constructs a level's main song from the indices `12` and `35` (the custom song ID)
+pub(super) fn main_song_from_raw(main_song: u8, custom_song: Option<u64>) -> Option<MainSong> {
+    if custom_song.is_some() {
+        None
+    } else {
+        Some(MainSong::from(main_song))
     }
 }
 
-impl<'de> Dash<'de> for Level<'de, LevelData<'de>, Option<u64>, u64> {
-    fn dash_deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let internal = InternalLevel::deserialize(deserializer)?;
-
-        let level_data = match (internal.index_4, internal.index_27, internal.index_28, internal.index_29) {
-            (Some(idx4), Some(idx27), Some(idx28), Some(idx29)) => LevelData {
-                level_data: InternalProxy::from_deserialize_proxy(idx4),
-                password: InternalProxy::from_deserialize_proxy(idx27),
-                time_since_upload: InternalProxy::from_deserialize_proxy(idx28),
-                time_since_update: InternalProxy::from_deserialize_proxy(idx29),
-                index_36: InternalProxy::from_deserialize_proxy(internal.index_36.unwrap_or_default()),
-                index_40: InternalProxy::from_deserialize_proxy(internal.index_40.unwrap_or_default()),
-                index_52: InternalProxy::from_deserialize_proxy(internal.index_52.unwrap_or_default()),
-                index_53: InternalProxy::from_deserialize_proxy(internal.index_53.unwrap_or_default()),
-                index_57: InternalProxy::from_deserialize_proxy(internal.index_57.unwrap_or_default()),
-            },
-            _ => return Err(D::Error::custom("Missing indices for level data!")),
-        };
-
-        Ok(Self {
-            level_id: InternalProxy::from_deserialize_proxy(internal.index_1),
-            name: InternalProxy::from_deserialize_proxy(internal.index_2),
-            description: InternalProxy::from_deserialize_proxy(internal.index_3),
-            version: InternalProxy::from_deserialize_proxy(internal.index_5),
-            creator: InternalProxy::from_deserialize_proxy(internal.index_6),
-            downloads: InternalProxy::from_deserialize_proxy(internal.index_10),
-            gd_version: InternalProxy::from_deserialize_proxy(internal.index_13),
-            likes: InternalProxy::from_deserialize_proxy(internal.index_14),
-            length: InternalProxy::from_deserialize_proxy(internal.index_15),
-            stars: InternalProxy::from_deserialize_proxy(internal.index_18),
-            featured: InternalProxy::from_deserialize_proxy(internal.index_19),
-            copy_of: InternalProxy::from_deserialize_proxy(internal.index_30),
-            two_player: InternalProxy::from_deserialize_proxy(internal.index_31),
-            custom_song: InternalProxy::from_deserialize_proxy(internal.index_35),
-            coin_amount: InternalProxy::from_deserialize_proxy(internal.index_37),
-            coins_verified: InternalProxy::from_deserialize_proxy(internal.index_38),
-            stars_requested: InternalProxy::from_deserialize_proxy(internal.index_39),
-            is_epic: InternalProxy::from_deserialize_proxy(internal.index_42),
-            object_amount: InternalProxy::from_deserialize_proxy(internal.index_45),
-            index_46: InternalProxy::from_deserialize_proxy(internal.index_46),
-            index_47: InternalProxy::from_deserialize_proxy(internal.index_47),
+pub(super) fn main_song_into_raw(main_song: &Option<MainSong>) -> u8 {
+    main_song.map(|song| song.main_song_id).unwrap_or(0)
+}
 
-            main_song: if internal.index_35.is_some() {
-                None
-            } else {
-                Some(MainSong::from(internal.index_12))
-            },
-            difficulty: if !internal.index_8 {
-                LevelRating::NotAvailable
-            } else if internal.index_25 {
-                LevelRating::Auto
-            } else if internal.index_17 {
-                LevelRating::Demon(DemonRating::from_response_value(internal.index_9))
-            } else {
-                LevelRating::from_response_value(internal.index_9)
-            },
+/// The serialization proxies of the indices `4`, `27`, `28`, `29`, `36`, `40`, `52`, `53` and `57`
+type RawLevelData<'a> = (
+    Option<Cow<'a, str>>,
+    Option<Cow<'a, str>>,
+    Option<Cow<'a, str>>,
+    Option<Cow<'a, str>>,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<&'a str>,
+);
+
+/// Trait for the possible types of [`Level::level_data`](super::Level::level_data)
+///
+/// Levels retrieved via `getGJLevels` do not contain any of the indices modelled by [`LevelData`],
+/// which is represented by `()`.
+pub(super) trait LevelDataFields<'a>: Sized {
+    #[allow(clippy::too_many_arguments)]
+    fn from_raw(
+        level_data: Option<&'a str>, password: Option<&'a str>, time_since_upload: Option<&'a str>, time_since_update: Option<&'a str>,
+        index_36: Option<&'a str>, index_40: Option<&'a str>, index_52: Option<&'a str>, index_53: Option<&'a str>,
+        index_57: Option<&'a str>,
+    ) -> Result<Self, &'static str>;
+
+    fn to_raw(&self) -> RawLevelData<'_>;
+}
 
-            level_data,
-        })
+impl<'a> LevelDataFields<'a> for () {
+    fn from_raw(
+        _: Option<&'a str>, _: Option<&'a str>, _: Option<&'a str>, _: Option<&'a str>, _: Option<&'a str>, _: Option<&'a str>,
+        _: Option<&'a str>, _: Option<&'a str>, _: Option<&'a str>,
+    ) -> Result<Self, &'static str> {
+        Ok(())
     }
 
-    fn dash_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        // We are in a manual impl, so we can do the .as_deref() trick to avoid needing two separate structs
-        let index_3 = self.description.to_serialize_proxy();
-        let index_4 = self.level_data.level_data.to_serialize_proxy();
-        let index_27 = self.level_data.password.to_serialize_proxy();
-
-        let internal = InternalLevel {
-            index_1: self.level_id.to_serialize_proxy(),
-            index_2: self.name.to_serialize_proxy(),
-            index_3: index_3.as_deref(),
-            index_5: self.version.to_serialize_proxy(),
-            index_6: self.creator.to_serialize_proxy(),
-            index_10: self.downloads.to_serialize_proxy(),
-            index_13: self.gd_version.to_serialize_proxy(),
-            index_14: self.likes.to_serialize_proxy(),
-            index_15: self.length.to_serialize_proxy(),
-            index_18: self.stars.to_serialize_proxy(),
-            index_19: self.featured.to_serialize_proxy(),
-            index_30: self.copy_of.to_serialize_proxy(),
-            index_31: self.two_player.to_serialize_proxy(),
-            index_35: self.custom_song.to_serialize_proxy(),
-            index_37: self.coin_amount.to_serialize_proxy(),
-            index_38: self.coins_verified.to_serialize_proxy(),
-            index_39: self.stars_requested.to_serialize_proxy(),
-            index_42: self.is_epic.to_serialize_proxy(),
-            index_45: self.object_amount.to_serialize_proxy(),
-            index_46: self.index_46.to_serialize_proxy(),
-            index_47: self.index_47.to_serialize_proxy(),
-
-            index_12: self.main_song.map(|song| song.main_song_id).unwrap_or(0),
-            index_25: self.difficulty == LevelRating::Auto,
-            index_8: self.difficulty != LevelRating::NotAvailable,
-            index_9: self.difficulty.into_response_value(),
-            index_17: self.difficulty.is_demon(),
-            index_43: match self.difficulty {
-                LevelRating::Demon(DemonRating::Easy) => 3,
-                LevelRating::Demon(DemonRating::Medium) => 4,
-                LevelRating::Demon(DemonRating::Hard) => 0,
-                LevelRating::Demon(DemonRating::Insane) => 5,
-                LevelRating::Demon(DemonRating::Extreme) => 6,
-                _ => 5,
-            },
-
-            index_4: Some(index_4.borrow()),
-            index_27: Some(index_27.borrow()),
-            index_28: Some(self.level_data.time_since_upload.to_serialize_proxy()),
-            index_29: Some(self.level_data.time_since_update.to_serialize_proxy()),
-            index_36: Some(self.level_data.index_36.to_serialize_proxy()),
-            index_40: Some(self.level_data.index_40.to_serialize_proxy()),
-            index_52: Some(self.level_data.index_52.to_serialize_proxy()),
-            index_53: Some(self.level_data.index_53.to_serialize_proxy()),
-            index_57: Some(self.level_data.index_57.to_serialize_proxy()),
-        };
-        internal.serialize(serializer)
+    fn to_raw(&self) -> RawLevelData<'_> {
+        (None, None, None, None, None, None, None, None, None)
     }
 }
 
-impl InternalProxy for LevelLength {
-    type DeserializeProxy = i32;
-    type SerializeProxy<'a> = i32
-    where
-        Self: 'a;
-
-    fn to_serialize_proxy(&self) -> Self::SerializeProxy<'_> {
-        match *self {
-            LevelLength::Unknown(unknown) => unknown,
-            LevelLength::Tiny => 0,
-            LevelLength::Short => 1,
-            LevelLength::Medium => 2,
-            LevelLength::Long => 3,
-            LevelLength::ExtraLong => 4,
-            LevelLength::Platformer => 5,
+impl<'a> LevelDataFields<'a> for LevelData<'a> {
+    fn from_raw(
+        level_data: Option<&'a str>, password: Option<&'a str>, time_since_upload: Option<&'a str>, time_since_update: Option<&'a str>,
+        index_36: Option<&'a str>, index_40: Option<&'a str>, index_52: Option<&'a str>, index_53: Option<&'a str>,
+        index_57: Option<&'a str>,
+    ) -> Result<Self, &'static str> {
+        match (level_data, password, time_since_upload, time_since_update) {
+            (Some(level_data), Some(password), Some(time_since_upload), Some(time_since_update)) => Ok(LevelData {
+                level_data: InternalProxy::from_deserialize_proxy(level_data),
+                password: InternalProxy::from_deserialize_proxy(password),
+                time_since_upload: InternalProxy::from_deserialize_proxy(time_since_upload),
+                time_since_update: InternalProxy::from_deserialize_proxy(time_since_update),
+                index_36: InternalProxy::from_deserialize_proxy(index_36.unwrap_or_default()),
+                index_40: InternalProxy::from_deserialize_proxy(index_40.unwrap_or_default()),
+                index_52: InternalProxy::from_deserialize_proxy(index_52.unwrap_or_default()),
+                index_53: InternalProxy::from_deserialize_proxy(index_53.unwrap_or_default()),
+                index_57: InternalProxy::from_deserialize_proxy(index_57.unwrap_or_default()),
+            }),
+            _ => Err("Missing indices for level data!"),
         }
     }
 
-    fn from_deserialize_proxy(from: Self::DeserializeProxy) -> Self {
-        match from {
-            0 => LevelLength::Tiny,
-            1 => LevelLength::Short,
-            2 => LevelLength::Medium,
-            3 => LevelLength::Long,
-            4 => LevelLength::ExtraLong,
-            5 => LevelLength::Platformer,
-            int => LevelLength::Unknown(int),
-        }
+    fn to_raw(&self) -> RawLevelData<'_> {
+        (
+            Some(self.level_data.to_serialize_proxy()),
+            Some(self.password.to_serialize_proxy()),
+            Some(self.time_since_upload.to_serialize_proxy()),
+            Some(self.time_since_update.to_serialize_proxy()),
+            Some(self.index_36.to_serialize_proxy()),
+            Some(self.index_40.to_serialize_proxy()),
+            Some(self.index_52.to_serialize_proxy()),
+            Some(self.index_53.to_serialize_proxy()),
+            Some(self.index_57.to_serialize_proxy()),
+        )
     }
 }
 
@@ -0,0 +1,215 @@
+//! Module containing types for iterating over the objects of a level one at a time, without
+//! collecting them into an [`Objects`] first
+
+use crate::{
+    model::level::{decompress_level_data, metadata::LevelMetadata, object::LevelObject, LevelCompression, LevelProcessError, Objects},
+    GJFormat, IntoOwned,
+};
+use std::str::SplitTerminator;
+
+/// Decompressed level data whose objects are only parsed on demand.
+///
+/// This is useful for aggregate queries (such as counting objects of a certain type), where
+/// holding a parsed [`LevelObject`] for every object in the level is unnecessary. The objects
+/// yielded by this type borrow from the decompressed level string held inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazyObjects {
+    decompressed: String,
+    compression: LevelCompression,
+}
+
+impl LazyObjects {
+    /// Decodes and decompresses the given level data, as it is provided at index `4` of the level
+    /// object (see [`LevelData::level_data`](crate::model::level::LevelData::level_data))
+    pub fn from_encoded(encoded: &str) -> Result<LazyObjects, LevelProcessError> {
+        decompress_level_data(encoded).map(|(decompressed, compression)| LazyObjects { decompressed, compression })
+    }
+
+    /// Wraps an already decompressed level string (`;`-separated objects, preceded by the metadata
+    /// section), such as the ones stored in local savefiles.
+    ///
+    /// As no compression was detected, [`LazyObjects::compression`] will report the default
+    /// compression.
+    pub fn from_decompressed(decompressed: String) -> LazyObjects {
+        LazyObjects {
+            decompressed,
+            compression: LevelCompression::default(),
+        }
+    }
+
+    /// The compression the level data was encoded with
+    pub fn compression(&self) -> LevelCompression {
+        self.compression
+    }
+
+    /// The decompressed level string
+    pub fn as_str(&self) -> &str {
+        &self.decompressed
+    }
+
+    /// Parses the metadata section of this level
+    pub fn metadata(&self) -> Result<LevelMetadata<'_>, LevelProcessError> {
+        parse_metadata(&self.decompressed)
+    }
+
+    /// Iterates over the objects in this level without parsing them
+    pub fn raw_objects(&self) -> RawObjects<'_> {
+        let mut objects = RawObjects::new(&self.decompressed);
+
+        // skip the metadata section
+        objects.sections.next();
+        objects
+    }
+
+    /// Iterates over the objects in this level, parsing each one as it is yielded
+    pub fn objects(&self) -> impl Iterator<Item = Result<LevelObject<'_>, LevelProcessError>> {
+        self.raw_objects().map(|object| object.parse())
+    }
+
+    /// Parses the entire level into an [`Objects`] struct
+    pub fn into_objects(self) -> Result<Objects, LevelProcessError> {
+        parse_level_string(&self.decompressed, self.compression)
+    }
+}
+
+fn parse_metadata(level_string: &str) -> Result<LevelMetadata<'_>, LevelProcessError> {
+    match level_string.split_terminator(';').next() {
+        Some(metadata) => LevelMetadata::from_gj_str(metadata).map_err(|err| LevelProcessError::Deserialize(err.to_string())),
+        None => Err(LevelProcessError::MissingMetadata),
+    }
+}
+
+/// Parses a decompressed level string into an [`Objects`] struct, recording the given compression
+pub(crate) fn parse_level_string(level_string: &str, compression: LevelCompression) -> Result<Objects, LevelProcessError> {
+    let meta = parse_metadata(level_string)?.into_owned();
+
+    let mut objects = RawObjects::new(level_string);
+    objects.sections.next();
+
+    objects
+        .map(|object| object.parse().map(LevelObject::into_owned))
+        .collect::<Result<_, _>>()
+        .map(|objects| Objects {
+            meta,
+            objects,
+            compression,
+        })
+}
+
+/// Iterator over unparsed objects, see [`LazyObjects::raw_objects`]
+#[derive(Debug, Clone)]
+pub struct RawObjects<'a> {
+    sections: SplitTerminator<'a, char>,
+}
+
+impl<'a> RawObjects<'a> {
+    /// Iterates over the objects in the given `;`-separated object string, which must not
+    /// contain a metadata section. These are for example the strings the level editor copies to the
+    /// clipboard.
+    pub fn new(object_string: &'a str) -> RawObjects<'a> {
+        RawObjects {
+            sections: object_string.split_terminator(';'),
+        }
+    }
+}
+
+impl<'a> Iterator for RawObjects<'a> {
+    type Item = RawObject<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.sections.next().map(RawObject)
+    }
+}
+
+/// A single unparsed object of a level, providing a view of its (borrowed) key-value pairs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawObject<'a>(&'a str);
+
+impl<'a> RawObject<'a> {
+    /// The raw string representation of this object
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Iterates over the key-value pairs of this object, in the order they appear in the level
+    /// string. A trailing key without a value is ignored.
+    pub fn pairs(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let mut tokens = self.0.split(',');
+
+        std::iter::from_fn(move || Some((tokens.next()?, tokens.next()?)))
+    }
+
+    /// Retrieves the value stored at the given key, if present
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.pairs().find(|&(k, _)| k == key).map(|(_, value)| value)
+    }
+
+    /// The ID of this object, if present and valid
+    pub fn id(&self) -> Option<u16> {
+        self.get("1")?.parse().ok()
+    }
+
+    /// Fully parses this object
+    pub fn parse(&self) -> Result<LevelObject<'a>, LevelProcessError> {
+        LevelObject::from_gj_str(self.0).map_err(|err| LevelProcessError::Deserialize(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::{
+            lazy::LazyObjects,
+            metadata::LevelMetadata,
+            object::{ids, speed::Speed, LevelObject},
+            LevelCompression, Objects,
+        },
+        GJFormat, IntoOwned, ThunkProcessor,
+    };
+
+    #[test]
+    fn lazy_iteration() {
+        let objects = Objects {
+            meta: LevelMetadata::default(),
+            objects: ["1,1,2,15,3,15", "1,901,2,45,3,15,10,0.5,51,3", "1,1,2,75,3,15,57,2"]
+                .iter()
+                .map(|raw| LevelObject::from_gj_str(raw).unwrap().into_owned())
+                .collect(),
+            compression: LevelCompression::default(),
+        };
+        let encoded = Objects::as_unprocessed(&objects).unwrap();
+        let lazy = LazyObjects::from_encoded(&encoded).unwrap();
+
+        assert_eq!(lazy.raw_objects().count(), 3);
+        assert_eq!(
+            lazy.raw_objects().filter(|object| object.id() == Some(ids::MOVE_TRIGGER)).count(),
+            1
+        );
+        assert_eq!(lazy.raw_objects().nth(2).unwrap().get("57"), Some("2"));
+        assert_eq!(lazy.raw_objects().nth(2).unwrap().get("51"), None);
+
+        for (lazy, eager) in lazy.objects().zip(&objects.objects) {
+            assert_eq!(&lazy.unwrap(), eager);
+        }
+
+        assert_eq!(lazy.into_objects().unwrap().objects, objects.objects);
+    }
+
+    #[test]
+    fn decompressed_level_string() {
+        let lazy = LazyObjects::from_decompressed("kA4,2,kA13,0;1,1,2,15,3,15;1,202,2,45,3,15;".to_owned());
+
+        assert_eq!(lazy.metadata().unwrap().starting_speed, Speed::Medium);
+        assert_eq!(
+            lazy.raw_objects().map(|object| object.id()).collect::<Vec<_>>(),
+            vec![Some(1), Some(202)]
+        );
+        assert_eq!(lazy.compression(), LevelCompression::default());
+    }
+
+    #[test]
+    fn invalid_compression() {
+        assert!(LazyObjects::from_encoded("").is_err());
+        assert!(LazyObjects::from_encoded("AAAA").is_err());
+    }
+}
@@ -0,0 +1,390 @@
+//! Module containing the model for levels as they are stored locally by the game, e.g. in
+//! `CCLocalLevels.dat`
+
+use std::{borrow::Cow, convert::TryFrom};
+
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+use variant_partial_eq::VariantPartialEq;
+
+use crate::{
+    model::{
+        level::{Featured, Level, LevelData, LevelLength, LevelRating, Objects, Password},
+        song::MainSong,
+        time::{RelativeTime, TimeUnit},
+        GameVersion,
+    },
+    serde::{Base64Decoder, PlistFormat, ProcessError, Thunk},
+    UnknownFields,
+};
+
+/// A level as stored locally by the game
+///
+/// This is the format of the levels created in the editor (stored in `CCLocalLevels.dat`), as well
+/// as of levels exported to `.gmd` files. Compared to [`Level`], it contains a lot of data only
+/// relevant to the player's own copy of the level (such as attempts or time spent in the editor),
+/// but none of the statistics the servers track.
+///
+/// ## GD Internals:
+/// Local levels are stored as plist dictionaries whose keys are of the form `k<number>`. Note that
+/// these numbers are unrelated to the indices of the same data in server responses. Keys
+/// dash-rs does not know about are preserved in [`LocalLevel::unknown_fields`] and written back
+/// out unchanged. Like the game does, values that are at their default are omitted when writing.
+#[derive(Debug, VariantPartialEq, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LocalLevel<'a> {
+    /// The ID of this level on the servers, or [`None`] if it was never uploaded
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k1`
+    #[dash(index = "k1")]
+    pub level_id: Option<u64>,
+
+    /// The level's name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k2`
+    #[dash(index = "k2")]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    /// The level's description, or [`None`] if the creator didn't set one
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k3` and encoded using urlsafe base 64.
+    #[dash(index = "k3")]
+    #[variant_compare = "crate::util::option_variant_eq"]
+    pub description: Option<Thunk<'a, Base64Decoder>>,
+
+    /// The level's actual data
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k4`, in the same format as index `4` of a downloaded level
+    #[dash(index = "k4")]
+    pub level_data: Thunk<'a, Objects>,
+
+    /// The name of the level's creator
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k5`
+    #[dash(index = "k5")]
+    pub creator_name: Cow<'a, str>,
+
+    /// The user ID of the level's creator
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k6`
+    #[dash(index = "k6")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub creator: u64,
+
+    /// The [`MainSong`] the level uses. Note that the game omits this value for the first main
+    /// song, and that it is meaningless if [`LocalLevel::custom_song`] is set.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k8`
+    #[dash(index = "k8")]
+    pub main_song: Option<MainSong>,
+
+    /// Whether the level has been verified (beaten in the editor)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k14`
+    #[dash(index = "k14")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub verified: bool,
+
+    /// Whether the level has been uploaded
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k15`
+    #[dash(index = "k15")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub uploaded: bool,
+
+    /// The level's version, see [`Level::version`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k16`
+    #[dash(index = "k16")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub version: u32,
+
+    /// The game version the level was last saved in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k17`
+    #[dash(index = "k17")]
+    pub gd_version: Option<GameVersion>,
+
+    /// The amount of attempts the player has made on this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k18`
+    #[dash(index = "k18")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub attempts: u32,
+
+    /// The player's best percentage in normal mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k19`
+    #[dash(index = "k19")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub normal_percentage: u8,
+
+    /// The player's best percentage in practice mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k20`
+    #[dash(index = "k20")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub practice_percentage: u8,
+
+    /// The length of this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k23`, using the same integer representation as index `15` of
+    /// a server response
+    #[dash(index = "k23")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub length: LevelLength,
+
+    /// The level's copy password, or [`None`] if the level cannot be copied
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k41`, in plain text. A value of `1` means that the level is
+    /// free to copy.
+    #[dash(index = "k41")]
+    pub password: Option<u32>,
+
+    /// The ID of the level this level is a copy of, or [`None`], if this level isn't a copy
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k42`
+    #[dash(index = "k42")]
+    pub copy_of: Option<u64>,
+
+    /// Whether this level is played in two-player mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k43`
+    #[dash(index = "k43")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub two_player: bool,
+
+    /// The id of the newgrounds song this level uses, or [`None`] if it uses a main song
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k45`
+    #[dash(index = "k45")]
+    pub custom_song: Option<u64>,
+
+    /// The amount of objects in this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k48`
+    #[dash(index = "k48")]
+    pub object_amount: Option<u32>,
+
+    /// The time spent editing this level, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k80`
+    #[dash(index = "k80")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub editor_time: u32,
+
+    /// The time spent editing the levels this level is a copy of, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k81`
+    #[dash(index = "k81")]
+    #[dash(default, skip_serializing_if = "crate::util::is_default")]
+    pub editor_time_copies: u32,
+
+    /// All keys of this level not mapped to any of the above fields (such as the object type key
+    /// `kCEK`)
+    #[dash(unknown_fields)]
+    pub unknown_fields: UnknownFields<'a>,
+}
+
+impl<'de> PlistFormat<'de> for LocalLevel<'de> {}
+
+impl<'a> LocalLevel<'a> {
+    /// The [`MainSong`] this level uses, or [`None`] if it uses a custom song
+    pub fn song(&self) -> Option<MainSong> {
+        match self.custom_song {
+            Some(_) => None,
+            None => Some(self.main_song.unwrap_or_else(|| MainSong::from(0))),
+        }
+    }
+}
+
+impl<'a> From<LocalLevel<'a>> for Level<'a, LevelData<'a>> {
+    /// Converts a local level into the format the servers use. All statistics only known to the
+    /// servers (e.g. downloads or likes) are left at their defaults.
+    fn from(local: LocalLevel<'a>) -> Self {
+        let password = match local.password {
+            None | Some(0) => Password::NoCopy,
+            Some(1) => Password::FreeCopy,
+            Some(password) => Password::PasswordCopy(password),
+        };
+
+        // Local levels have never been uploaded, so we just pretend it happened right now
+        let just_now = RelativeTime {
+            unit: TimeUnit::Second,
+            amount: 0,
+        };
+
+        Level {
+            level_id: local.level_id.unwrap_or_default(),
+            main_song: local.song(),
+            name: local.name,
+            description: local.description,
+            version: local.version,
+            creator: local.creator,
+            difficulty: LevelRating::NotAvailable,
+            downloads: 0,
+            gd_version: local.gd_version.unwrap_or(GameVersion::Unknown),
+            likes: 0,
+            length: local.length,
+            stars: 0,
+            featured: Featured::NotFeatured,
+            copy_of: local.copy_of,
+            two_player: local.two_player,
+            custom_song: local.custom_song,
+            coin_amount: 0,
+            coins_verified: false,
+            stars_requested: None,
+            is_epic: false,
+            object_amount: local.object_amount,
+            index_46: None,
+            index_47: None,
+            level_data: LevelData {
+                level_data: local.level_data,
+                password: Thunk::Processed(password),
+                time_since_upload: Thunk::Processed(just_now),
+                time_since_update: Thunk::Processed(just_now),
+                index_36: Cow::Borrowed(""),
+                index_40: Cow::Borrowed(""),
+                index_52: Cow::Borrowed(""),
+                index_53: Cow::Borrowed(""),
+                index_57: Cow::Borrowed(""),
+            },
+        }
+    }
+}
+
+impl<'a> TryFrom<Level<'a, LevelData<'a>>> for LocalLevel<'a> {
+    type Error = ProcessError;
+
+    /// Converts a downloaded level into the format the game stores levels in locally, as if the
+    /// level had just been copied. All data only known to the local client (e.g. the amount of
+    /// attempts) is left at its defaults, and the creator's name is left empty, as it is not part
+    /// of [`Level`].
+    ///
+    /// Fails if the level's password cannot be decoded.
+    fn try_from(level: Level<'a, LevelData<'a>>) -> Result<Self, Self::Error> {
+        let password = match level.level_data.password.into_processed()? {
+            Password::NoCopy => None,
+            Password::FreeCopy => Some(1),
+            Password::PasswordCopy(password) => Some(password),
+        };
+
+        Ok(LocalLevel {
+            level_id: Some(level.level_id).filter(|&id| id != 0),
+            name: level.name,
+            description: level.description,
+            level_data: level.level_data.level_data,
+            creator_name: Cow::Borrowed(""),
+            creator: level.creator,
+            main_song: level.main_song,
+            verified: true,
+            uploaded: false,
+            version: level.version,
+            gd_version: Some(level.gd_version),
+            attempts: 0,
+            normal_percentage: 0,
+            practice_percentage: 0,
+            length: level.length,
+            password,
+            copy_of: level.copy_of,
+            two_player: level.two_player,
+            custom_song: level.custom_song,
+            object_amount: level.object_amount,
+            editor_time: 0,
+            editor_time_copies: 0,
+            unknown_fields: UnknownFields::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::{
+        model::level::{local::LocalLevel, Level, LevelData, LevelLength, Password},
+        PlistFormat,
+    };
+
+    const LOCAL_LEVEL: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>kCEK</k><i>4</i><k>k1</k><i>63355989</i><k>k2</k><s>Test Level</s><k>k3</k><s>SGVsbG8gV29ybGQ=</s><k>k4</k><s>H4sIAAAAAAAAAyXFQQ3AMAwEQUL38Nq1FCmvYiiAAxAKBd9U-cysp4bwFU6T7XJ2G055KmP6Zzg8zKt1U4qJUIpWbeYHmESMV00AAAA=</s><k>k5</k><s>stadust</s><k>k6</k><i>8451</i><k>k8</k><i>3</i><k>k14</k><t /><k>k16</k><i>2</i><k>k17</k><i>22</i><k>k18</k><i>120</i><k>k19</k><i>47</i><k>k23</k><i>2</i><k>k41</k><i>1</i><k>k80</k><i>3600</i></dict></plist>"#;
+
+    #[test]
+    fn deserialize_local_level() {
+        let level = LocalLevel::from_plist_str(LOCAL_LEVEL).unwrap();
+
+        assert_eq!(level.level_id, Some(63355989));
+        assert_eq!(level.name, "Test Level");
+        assert_eq!(level.creator_name, "stadust");
+        assert_eq!(level.song().map(|song| song.main_song_id), Some(3));
+        assert!(level.verified);
+        assert!(!level.uploaded);
+        assert_eq!(level.attempts, 120);
+        assert_eq!(level.normal_percentage, 47);
+        assert_eq!(level.length, LevelLength::Medium);
+        assert_eq!(level.password, Some(1));
+        assert_eq!(level.copy_of, None);
+        assert_eq!(level.editor_time, 3600);
+        assert_eq!(level.unknown_fields.get("kCEK"), Some("<i>4</i>"));
+        assert_eq!(level.unknown_fields.len(), 1);
+
+        let xml = level.to_plist_string().unwrap();
+
+        assert!(xml.contains("<k>kCEK</k><i>4</i>"));
+        assert_eq!(LocalLevel::from_plist_str(&xml).unwrap().unknown_fields, level.unknown_fields);
+        assert_eq!(level.level_data.into_processed().unwrap().objects.len(), 1);
+    }
+
+    #[test]
+    fn convert_to_and_from_level() {
+        let local = LocalLevel::from_plist_str(LOCAL_LEVEL).unwrap();
+        let level = Level::<LevelData>::from(local);
+
+        assert_eq!(level.level_id, 63355989);
+        assert_eq!(level.creator, 8451);
+        assert_eq!(level.custom_song, None);
+        assert_eq!(level.main_song.map(|song| song.main_song_id), Some(3));
+        assert_eq!(level.description.as_ref().unwrap().as_processed().unwrap().as_ref(), "Hello World");
+
+        let local = LocalLevel::try_from(level).unwrap();
+
+        assert_eq!(local.level_id, Some(63355989));
+        assert_eq!(local.password, Some(1));
+        assert_eq!(local.version, 2);
+
+        let xml = local.to_plist_string().unwrap();
+        let round_tripped = LocalLevel::from_plist_str(&xml).unwrap();
+
+        assert_eq!(round_tripped.name, "Test Level");
+        assert_eq!(round_tripped.gd_version, local.gd_version);
+        assert_eq!(
+            Level::<LevelData>::from(round_tripped)
+                .level_data
+                .password
+                .into_processed()
+                .unwrap(),
+            Password::FreeCopy
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use crate::{model::level::object::speed::Speed, GJFormat};
-use dash_rs_derive::Dash;
+use crate::{
+    model::level::{guidelines::Guidelines, object::speed::Speed, LevelProcessError},
+    GJFormat, IntoOwned, Thunk, ThunkProcessor, UnknownFields,
+};
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use variant_partial_eq::VariantPartialEq;
 
-#[derive(Debug, PartialEq, Clone, Default, Copy, Serialize, Deserialize, Dash)]
-pub struct LevelMetadata {
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LevelMetadata<'a> {
     #[dash(index = "kA4")]
-    #[dash(default = "one")]
+    #[dash(default)]
     pub starting_speed: Speed,
 
     #[dash(index = "kA13")]
@@ -31,31 +36,348 @@ pub struct LevelMetadata {
     #[dash(index = "kA11")]
     #[dash(default)]
     pub start_gravity_inverted: bool,
-    // ... other fields in the metadata section ...
+
+    /// The game mode the player starts the level in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA2`
+    #[dash(index = "kA2")]
+    #[dash(default)]
+    pub starting_game_mode: GameMode,
+
+    /// Whether the player starts the level in mini mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA3`
+    #[dash(index = "kA3")]
+    #[dash(default)]
+    pub starting_mini: bool,
+
+    /// Whether this level is a platformer level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA22`
+    #[dash(index = "kA22")]
+    #[dash(default)]
+    pub platformer: bool,
+
+    /// The index of the background texture used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA6`
+    #[dash(index = "kA6")]
+    #[dash(default)]
+    pub background_texture: u8,
+
+    /// The index of the ground texture used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA7`
+    #[dash(index = "kA7")]
+    #[dash(default)]
+    pub ground_texture: u8,
+
+    /// The index of the ground line style used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA17`
+    #[dash(index = "kA17")]
+    #[dash(default)]
+    pub ground_line: u8,
+
+    /// The index of the font used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA18`
+    #[dash(index = "kA18")]
+    #[dash(default)]
+    pub font: u8,
+
+    /// The song guidelines placed in the editor
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA14`, see [`Guidelines`] for the format
+    #[dash(index = "kA14")]
+    #[dash(default)]
+    pub guidelines: Thunk<'a, Guidelines>,
+
+    /// The color channels defined for this level. Only present in levels saved in version 2.0 or
+    /// newer, older levels store their colors at indices `kS1` to `kS37` (which are not mapped by
+    /// dash-rs).
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kS38`, as a `|`-separated list of color channels.
+    #[dash(index = "kS38")]
+    #[dash(default, skip_serializing_if = "Option::is_none")]
+    #[variant_compare = "crate::util::option_variant_eq"]
+    pub color_channels: Option<Thunk<'a, ColorChannels>>,
+
+    /// The page of the color selection menu the editor was on when the level was last saved
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kS39`
+    #[dash(index = "kS39")]
+    #[dash(default)]
+    pub color_page: u8,
+
+    /// All indices of the metadata section not mapped to any of the above fields
+    #[dash(unknown_fields)]
+    pub unknown_fields: UnknownFields<'a>,
+}
+
+impl Default for LevelMetadata<'_> {
+    fn default() -> Self {
+        LevelMetadata {
+            starting_speed: Speed::default(),
+            song_offset: 0.0,
+            song_fade_in: false,
+            song_fade_out: false,
+            dual_start: false,
+            two_player_controls: false,
+            start_gravity_inverted: false,
+            starting_game_mode: GameMode::default(),
+            starting_mini: false,
+            platformer: false,
+            background_texture: 0,
+            ground_texture: 0,
+            ground_line: 0,
+            font: 0,
+            guidelines: Thunk::Processed(Guidelines::default()),
+            color_channels: None,
+            color_page: 0,
+            unknown_fields: UnknownFields::new(),
+        }
+    }
 }
 
-impl<'de> GJFormat<'de> for LevelMetadata {
+impl<'de> GJFormat<'de> for LevelMetadata<'de> {
     const DELIMITER: &'static str = ",";
     const MAP_LIKE: bool = true;
 }
 
-fn one() -> u8 {
-    1
+/// Enum modelling the different game modes a player can start a level in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Dash)]
+pub enum GameMode {
+    #[default]
+    #[dash(value = 0)]
+    Cube,
+    #[dash(value = 1)]
+    Ship,
+    #[dash(value = 2)]
+    Ball,
+    #[dash(value = 3)]
+    Ufo,
+    #[dash(value = 4)]
+    Wave,
+    #[dash(value = 5)]
+    Robot,
+    #[dash(value = 6)]
+    Spider,
+    #[dash(value = 7)]
+    Swing,
+    Unknown(u8),
+}
+
+/// Enum describing which player color (if any) a color channel follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Dash)]
+pub enum PlayerColor {
+    #[default]
+    #[dash(value = -1)]
+    None,
+    #[dash(value = 1)]
+    Player1,
+    #[dash(value = 2)]
+    Player2,
+    Unknown(i8),
+}
+
+/// Struct representing a single color channel as defined in a level's metadata section
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct ColorChannel<'a> {
+    /// The ID of this color channel. Values of 1000 and above are special channels (e.g. `1000` is
+    /// the background and `1004` the color of object outlines)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    #[dash(index = 6)]
+    pub id: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    #[dash(index = 1)]
+    #[dash(default)]
+    pub red: u8,
+
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    #[dash(index = 2)]
+    #[dash(default)]
+    pub green: u8,
+
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    #[dash(index = 3)]
+    #[dash(default)]
+    pub blue: u8,
+
+    /// The player color this channel follows, overriding the RGB values above
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`. It is omitted if the channel does not follow a player
+    /// color.
+    #[dash(index = 4)]
+    #[dash(default = "no_player_color")]
+    pub player_color: PlayerColor,
+
+    /// ## GD Internals:
+    /// This value is provided at index `5`
+    #[dash(index = 5)]
+    #[dash(default)]
+    pub blending: bool,
+
+    /// The opacity of this color channel, between 0 and 1
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`
+    #[dash(index = 7)]
+    #[dash(default = "full_opacity")]
+    pub opacity: f32,
+
+    /// The ID of the color channel this channel copies its color from, or `0` if it does not copy
+    /// any channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    #[dash(index = 9)]
+    #[dash(default)]
+    pub copied_channel: u16,
+
+    /// The HSV adjustment applied to the copied color, as an `a`-separated string
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    #[dash(index = 10)]
+    #[dash(default, skip_serializing_if = "Option::is_none")]
+    #[variant_compare = "crate::util::option_variant_eq"]
+    pub copied_hsv: Option<Cow<'a, str>>,
+
+    /// Whether the opacity of the copied channel is used instead of [`ColorChannel::opacity`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `17`
+    #[dash(index = 17)]
+    #[dash(default)]
+    pub copy_opacity: bool,
+
+    /// All indices of this color channel not mapped to any of the above fields
+    #[dash(unknown_fields)]
+    pub unknown_fields: UnknownFields<'a>,
+}
+
+impl<'de> GJFormat<'de> for ColorChannel<'de> {
+    const DELIMITER: &'static str = "_";
+    const MAP_LIKE: bool = true;
+}
+
+fn full_opacity() -> f32 {
+    1.0
+}
+
+fn no_player_color() -> i8 {
+    -1
+}
+
+/// [`ThunkProcessor`] for the list of [`ColorChannel`]s stored in a level's metadata
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, Copy)]
+pub struct ColorChannels;
+
+impl ThunkProcessor for ColorChannels {
+    type Error = LevelProcessError;
+    type Output<'a> = Vec<ColorChannel<'a>>;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        fn parse(channels: &str) -> Result<Vec<ColorChannel<'_>>, LevelProcessError> {
+            channels
+                .split_terminator('|')
+                .map(ColorChannel::from_gj_str)
+                .collect::<Result<_, _>>()
+                .map_err(|err| LevelProcessError::Deserialize(err.to_string()))
+        }
+
+        match unprocessed {
+            Cow::Borrowed(channels) => parse(channels),
+            Cow::Owned(channels) => Ok(parse(&channels)?.into_iter().map(ColorChannel::into_owned).collect()),
+        }
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        let mut bytes = Vec::new();
+
+        for channel in processed {
+            channel.write_gj(&mut bytes)?;
+            bytes.push(b'|');
+        }
+
+        // We only ever write valid UTF-8
+        Ok(Cow::Owned(String::from_utf8(bytes).unwrap()))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
 }
 
-// starting_speed(index = kA4),
-// song_offset(index = kA13),
-// fade_in(index = kA15),
-// fade_out(index = kA16),
-// song guidelines: kA16
-// background texture index: kA6
-// ground texture index: kA7
-// ground line index: kA17
-// font: kA18
-// color page (???): kS39
-// starting game mode: kA2
-// starting size: kA3
-// dual_start(index = kA8),
 // level/start pos (???): kA9
-// two_player_controls(index = kA10),
-// start_gravity_inverted(index = kA11, optional),
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::metadata::{GameMode, LevelMetadata, PlayerColor},
+        GJFormat,
+    };
+
+    const METADATA: &str = "kS38,1_40_2_125_3_255_11_255_12_255_13_255_6_1000_7_1_15_1_18_0_8_1|1_255_2_75_3_0_11_255_12_255_13_255_4_-1_6_1005_5_1_7_0.5_15_1_18_0_8_1|1_0_2_0_3_0_6_1_7_1_9_1005_10_20a1a1a0a0_17_1|,kA13,0,kA15,0,kA16,0,kA14,,kA6,3,kA7,1,kA17,2,kA18,5,kS39,1,kA2,4,kA3,1,kA8,0,kA4,1,kA9,0,kA10,0,kA11,0";
+
+    #[test]
+    fn deserialize_metadata() {
+        let mut metadata = LevelMetadata::from_gj_str(METADATA).unwrap();
+
+        assert_eq!(metadata.starting_game_mode, GameMode::Wave);
+        assert!(metadata.starting_mini);
+        assert_eq!(metadata.background_texture, 3);
+        assert_eq!(metadata.ground_texture, 1);
+        assert_eq!(metadata.ground_line, 2);
+        assert_eq!(metadata.font, 5);
+        assert_eq!(metadata.color_page, 1);
+        assert_eq!(metadata.unknown_fields.get("kA9"), Some("0"));
+
+        let channels = metadata.color_channels.as_mut().unwrap().process().unwrap();
+
+        assert_eq!(channels.len(), 3);
+        assert_eq!(
+            (channels[0].id, channels[0].red, channels[0].green, channels[0].blue),
+            (1000, 40, 125, 255)
+        );
+        assert_eq!(channels[0].player_color, PlayerColor::None);
+        assert_eq!(channels[1].opacity, 0.5);
+        assert!(channels[1].blending);
+        assert_eq!(channels[2].copied_channel, 1005);
+        assert_eq!(channels[2].copied_hsv.as_deref(), Some("20a1a1a0a0"));
+        assert!(channels[2].copy_opacity);
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let mut metadata = LevelMetadata::from_gj_str(METADATA).unwrap();
+        metadata.color_channels.as_mut().unwrap().process().unwrap();
+
+        let mut written = Vec::new();
+        metadata.write_gj(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+
+        let mut reparsed = LevelMetadata::from_gj_str(&written).unwrap();
+        reparsed.color_channels.as_mut().unwrap().process().unwrap();
+
+        assert_eq!(metadata, reparsed);
+    }
+}
@@ -11,17 +11,25 @@ use thiserror::Error;
 use variant_partial_eq::VariantPartialEq;
 
 use base64::{engine::general_purpose::URL_SAFE, Engine};
-use flate2::read::{GzDecoder, GzEncoder, ZlibDecoder};
+use dash_rs_derive::{Dash, IntoOwned};
+use flate2::{
+    read::{GzDecoder, ZlibDecoder, ZlibEncoder},
+    GzBuilder,
+};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
     model::{
         creator::Creator,
         level::{
+            index::ObjectIndex,
+            lazy::{parse_level_string, LazyObjects},
             metadata::LevelMetadata,
-            object::{speed::Speed, LevelObject, ObjectData},
+            object::{ids, speed::Speed, write_objects, LevelObject, ObjectData},
+            timing::LevelTiming,
         },
         song::{MainSong, NewgroundsSong},
+        time::RelativeTime,
         GameVersion,
     },
     serde::{Base64Decoder, ProcessError, Thunk, ThunkProcessor},
@@ -32,12 +40,19 @@ use flate2::Compression;
 // use flate2::read::GzDecoder;
 // use std::io::Read;
 
+pub mod daily;
+pub mod guidelines;
+pub mod index;
 mod internal;
+pub mod lazy;
+pub mod local;
 pub mod metadata;
 pub mod object;
+pub mod spatial;
+pub mod timing;
 
 /// Enum representing the possible level lengths known to dash-rs
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize, Dash)]
 pub enum LevelLength {
     /// Enum variant that's used by the [`From<i32>`](From) impl for when an
     /// unrecognized value is passed
@@ -48,6 +63,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `0` in both requests and
     /// responses
+    #[dash(value = 0)]
     Tiny,
 
     /// Short
@@ -55,6 +71,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `1` in both requests and
     /// responses
+    #[dash(value = 1)]
     Short,
 
     /// Medium
@@ -62,6 +79,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `2` in both requests and
     /// responses
+    #[dash(value = 2)]
     Medium,
 
     /// Long
@@ -69,6 +87,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `3` in both requests and
     /// responses
+    #[dash(value = 3)]
     Long,
 
     /// Extra Long, sometime referred to as `XL`
@@ -76,6 +95,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `4` in both requests and
     /// responses
+    #[dash(value = 4)]
     ExtraLong,
 
     /// Platformer levels (referred to as "Plat." on the level overview screens)
@@ -83,6 +103,7 @@ pub enum LevelLength {
     /// ## GD Internals:
     /// This variant is represented by the value `5` in both requests and
     /// responses
+    #[dash(value = 5)]
     Platformer,
 }
 
@@ -377,6 +398,8 @@ impl Password {
     }
 }
 
+crate::into_owned_identity!(Password);
+
 impl ThunkProcessor for Password {
     type Error = ProcessError;
     type Output<'a> = Password;
@@ -454,18 +477,22 @@ pub type ListedLevel<'a> = Level<'a, (), Option<NewgroundsSong<'a>>, Option<Crea
 /// The following indices aren't used by the Geometry Dash servers: `11`, `16`,
 /// `17`, `20`, `21`, `22`, `23`, `24`, `26`, `31`, `32`, `33`, `34`, `40`,
 /// `41`, `44`
-#[derive(Debug, VariantPartialEq, Serialize, Deserialize)]
+#[derive(Debug, VariantPartialEq, Serialize, Deserialize, Dash, IntoOwned)]
+#[dash(instance(Data = ()))]
+#[dash(instance)]
 pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// The level's unique level id
     ///
     /// ## GD Internals:
     /// This value is provided at index `1`.
+    #[dash(index = 1)]
     pub level_id: u64,
 
     /// The level's name
     ///
     /// ## GD Internals:
     /// This value is provided at index `2`.
+    #[dash(index = 2)]
     #[serde(borrow)]
     pub name: Cow<'a, str>,
 
@@ -474,6 +501,7 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `3` and encoded using urlsafe base 64.
+    #[dash(index = 3)]
     #[variant_compare = "crate::util::option_variant_eq"]
     pub description: Option<Thunk<'a, Base64Decoder>>,
 
@@ -482,12 +510,14 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `5`.
+    #[dash(index = 5)]
     pub version: u32,
 
     /// The ID of the level's creator
     ///
     /// ## GD Internals:
     /// This value is provided at index `6`.
+    #[dash(index = 6)]
     pub creator: User,
 
     /// The difficulty of this [`Level`]
@@ -500,12 +530,22 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// If index 25 is set to true, the level is an auto level and the value at
     /// index 9 is some nonsense, in which case it is ignored.
+    #[dash(composite(
+        from = "crate::model::level::internal::difficulty_from_raw",
+        into = "crate::model::level::internal::difficulty_into_raw"
+    ))]
+    #[dash(raw(index = 25, ty = bool, serialize_with = "crate::util::false_to_empty_string"))]
+    #[dash(raw(index = 8, ty = bool, serialize_with = "crate::util::true_to_ten"))]
+    #[dash(raw(index = 9, ty = i32))]
+    #[dash(raw(index = 17, ty = bool, serialize_with = "crate::util::false_to_empty_string"))]
+    #[dash(raw(index = 43, ty = u8))]
     pub difficulty: LevelRating,
 
     /// The amount of downloads
     ///
     /// ## GD Internals:
     /// This value is provided at index `10`
+    #[dash(index = 10)]
     pub downloads: u32,
 
     /// The [`MainSong`] the level uses, if any.
@@ -516,18 +556,26 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// without that information, a value of `0` for
     /// this field could either mean the level uses `Stereo Madness` or no
     /// main song.
+    #[dash(composite(
+        from = "crate::model::level::internal::main_song_from_raw",
+        into = "crate::model::level::internal::main_song_into_raw"
+    ))]
+    #[dash(raw(index = 12, ty = u8))]
+    #[dash(depends_on = 35)]
     pub main_song: Option<MainSong>,
 
     /// The gd version the request was uploaded/last updated in.
     ///
     /// ## GD Internals:
     /// This value is provided at index `13`
+    #[dash(index = 13)]
     pub gd_version: GameVersion,
 
     /// The amount of likes this [`Level`] has received
     ///
     /// ## GD Internals:
     /// This value is provided at index `14`
+    #[dash(index = 14)]
     pub likes: i32,
 
     /// The length of this [`Level`]
@@ -535,6 +583,7 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// ## GD Internals:
     /// This value is provided as an integer representation of the
     /// [`LevelLength`] struct at index `15`
+    #[dash(index = 15)]
     pub length: LevelLength,
 
     /// The amount of stars completion of this [`Level`] awards. In the case of a platformer level,
@@ -542,12 +591,14 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `18`
+    #[dash(index = 18)]
     pub stars: u8,
 
     /// This [`Level`]s featured state
     ///
     /// ## GD Internals:
     /// This value is provided at index `19`
+    #[dash(index = 19)]
     pub featured: Featured,
 
     /// The ID of the level this [`Level`] is a copy of, or [`None`], if
@@ -555,12 +606,15 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `30`
+    #[dash(index = 30)]
+    #[dash(with = "crate::util::default_to_none")]
     pub copy_of: Option<u64>,
 
     /// Value indicating whether this level is played in two-player mode
     ///
     /// ## GD Internals:
     /// This value is provided at index `31` and actually sanely encoded
+    #[dash(index = 31)]
     pub two_player: bool,
 
     /// The id of the newgrounds song this [`Level`] uses, or [`None`]
@@ -569,12 +623,15 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// ## GD Internals:
     /// This value is provided at index `35`, and a value of `0` means, that no
     /// custom song is used.
+    #[dash(index = 35)]
+    #[dash(with = "crate::util::default_to_none")]
     pub custom_song: Song,
 
     /// The amount of coins in this [`Level`]
     ///
     /// ## GD Internals:
     /// This value is provided at index `37`
+    #[dash(index = 37)]
     pub coin_amount: u8,
 
     /// Value indicating whether the user coins (if present) in this
@@ -582,6 +639,7 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `38`, as an integer
+    #[dash(index = 38)]
     pub coins_verified: bool,
 
     /// The amount of stars the level creator has requested when uploading this
@@ -590,12 +648,15 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// ## GD Internals:
     /// This value is provided at index `39`, and a value of `0` means no stars
     /// were requested
+    #[dash(index = 39)]
+    #[dash(with = "crate::util::default_to_none")]
     pub stars_requested: Option<u8>,
 
     /// Value indicating whether this [`Level`] is epic
     ///
     /// ## GD Internals:
     /// This value is provided at index `42`, as an integer
+    #[dash(index = 42)]
     pub is_epic: bool,
 
     /// The amount of objects in this [`Level`]. Note that a value of `None` _does not_ mean
@@ -605,6 +666,8 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     /// ## GD Internals:
     /// This value is provided at index `45`, although only for levels uploaded
     /// in version 2.1 or later. For all older levels this is always `None`
+    #[dash(index = 45)]
+    #[dash(with = "crate::util::default_to_none")]
     pub object_amount: Option<u32>,
 
     /// According to the GDPS source this is always `1`, although that is
@@ -612,6 +675,7 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `46` and seems to be an integer
+    #[dash(index = 46)]
     pub index_46: Option<Cow<'a, str>>,
 
     /// According to the GDPS source, this is always `2`, although that is
@@ -619,11 +683,25 @@ pub struct Level<'a, Data = LevelData<'a>, Song = Option<u64>, User = u64> {
     ///
     /// ## GD Internals:
     /// This value is provided at index `47` and seems to be an integer
+    #[dash(index = 47)]
     pub index_47: Option<Cow<'a, str>>,
 
     /// Additional data about this level that can be retrieved by downloading the level.
     ///
     /// This is [`None`] for levels retrieved via the "overview" endpoint `getGJLevels`.
+    #[dash(composite(
+        try_from = "crate::model::level::internal::LevelDataFields::from_raw",
+        into = "crate::model::level::internal::LevelDataFields::to_raw"
+    ))]
+    #[dash(raw(index = 4, ty = Option<Thunk<'a, Objects>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 27, ty = Option<Thunk<'a, Password>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 28, ty = Option<Thunk<'a, RelativeTime>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 29, ty = Option<Thunk<'a, RelativeTime>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 36, ty = Option<Cow<'a, str>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 40, ty = Option<Cow<'a, str>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 52, ty = Option<Cow<'a, str>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 53, ty = Option<Cow<'a, str>>, skip_serializing_if = "Option::is_none"))]
+    #[dash(raw(index = 57, ty = Option<Cow<'a, str>>, skip_serializing_if = "Option::is_none"))]
     pub level_data: Data,
 }
 
@@ -643,7 +721,7 @@ where
 }
 
 /// Struct encapsulating the additional level data returned when actually downloading a level
-#[derive(Debug, VariantPartialEq, Serialize, Deserialize)]
+#[derive(Debug, VariantPartialEq, Serialize, Deserialize, IntoOwned)]
 pub struct LevelData<'a> {
     /// The level's actual data.
     ///
@@ -659,21 +737,19 @@ pub struct LevelData<'a> {
     /// [`Password`] variants
     pub password: Thunk<'a, Password>,
 
-    /// The time passed since the `Level` was uploaded, as a string. Note that these strings are
-    /// very imprecise, as they are only of the form "x months ago", or similar.
-    ///
-    /// TODO: Parse these into an enum
+    /// The time passed since the `Level` was uploaded. Note that these values are very imprecise,
+    /// as they are only of the form "x months ago", or similar.
     ///
     /// ## GD Internals:
     /// This value is provided at index `28`
-    pub time_since_upload: Cow<'a, str>,
+    pub time_since_upload: Thunk<'a, RelativeTime>,
 
-    /// The time passed since the `Level` was last updated, as a string. Note that these strings are
-    /// very imprecise, as they are only of the form "x months ago", or similar.
+    /// The time passed since the `Level` was last updated. Note that these values are very
+    /// imprecise, as they are only of the form "x months ago", or similar.
     ///
     /// ## GD Internals:
     /// This value is provided at index `29`
-    pub time_since_update: Cow<'a, str>,
+    pub time_since_update: Thunk<'a, RelativeTime>,
 
     /// According to the GDPS source, this is a value called `extraString`
     ///
@@ -690,10 +766,118 @@ pub struct LevelData<'a> {
     pub index_57: Cow<'a, str>,
 }
 
+/// The processed level data of a level
+///
+/// Since the level data is compressed, the objects in here cannot borrow from the level data string
+/// they were parsed from.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Objects {
-    pub meta: LevelMetadata,
-    pub objects: Vec<LevelObject>,
+    pub meta: LevelMetadata<'static>,
+    pub objects: Vec<LevelObject<'static>>,
+
+    /// The compression the level data was encoded with when it was parsed. This is also the
+    /// compression used when converting these objects back into level data.
+    #[serde(default)]
+    pub compression: LevelCompression,
+}
+
+/// The formats level data can be compressed in
+///
+/// ## GD Internals:
+/// The game detects the format when decompressing level data, meaning it accepts either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressionScheme {
+    /// zlib format, only used by very old levels
+    Zlib,
+
+    /// gzip format, used by all newer levels. The operating system field of the gzip header is
+    /// recorded and written back when compressing. The header's modification time and file name
+    /// are not recorded.
+    Gzip { operating_system: u8 },
+}
+
+/// Describes how level data is compressed
+///
+/// The default is gzip at compression level 6, with the gzip header indicating Windows as the
+/// operating system. This is what the game itself produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelCompression {
+    pub scheme: CompressionScheme,
+
+    /// The zlib compression level, from `0` (no compression) to `9` (best compression).
+    ///
+    /// When detected from existing level data, this is a best-effort guess based on the hints
+    /// the zlib and gzip headers provide, as neither format stores the exact level used.
+    pub level: u32,
+}
+
+impl LevelCompression {
+    /// Returns this compression with its compression level replaced by the given one
+    pub fn with_level(self, level: u32) -> LevelCompression {
+        LevelCompression { level, ..self }
+    }
+
+    /// Compresses and base64-encodes the given (decompressed) level string.
+    ///
+    /// Compressing a level string using the compression detected while decompressing it uses the
+    /// same compression format and level (as far as it could be guessed) as the original level
+    /// data. This does not guarantee byte for byte identical output: gzip headers are always written
+    /// with a modification time of `0` and without a file name, regardless of what the original
+    /// header contained.
+    pub fn compress(&self, level_string: &[u8]) -> Result<String, LevelProcessError> {
+        let level = Compression::new(self.level);
+        let mut compressed = Vec::new();
+
+        match self.scheme {
+            CompressionScheme::Gzip { operating_system } => GzBuilder::new()
+                .operating_system(operating_system)
+                .read(level_string, level)
+                .read_to_end(&mut compressed)?,
+            CompressionScheme::Zlib => ZlibEncoder::new(level_string, level).read_to_end(&mut compressed)?,
+        };
+
+        Ok(URL_SAFE.encode(compressed))
+    }
+
+    /// Guesses the compression used for the given (base64 decoded) level data
+    fn detect(compressed: &[u8]) -> Result<LevelCompression, LevelProcessError> {
+        match compressed {
+            // gz magic bytes. Byte 8 is the XFL field ('2' means the slowest, '4' the fastest
+            // algorithm was used), byte 9 the operating system.
+            [0x1f, 0x8b, _, _, _, _, _, _, xfl, operating_system, ..] => Ok(LevelCompression {
+                scheme: CompressionScheme::Gzip {
+                    operating_system: *operating_system,
+                },
+                level: match xfl {
+                    2 => 9,
+                    4 => 1,
+                    _ => 6,
+                },
+            }),
+            // There's no such thing as "zlib magic bytes", but the first byte stores some information about how the data is compressed.
+            // '0x78' is the first byte for the compression method robtop used (note: this is only used for very old levels, as he switched
+            // to gz for newer levels). The upper two bits of the second byte indicate the compression level.
+            [0x78, flags, ..] => Ok(LevelCompression {
+                scheme: CompressionScheme::Zlib,
+                level: match flags >> 6 {
+                    0 => 1,
+                    1 => 5,
+                    2 => 6,
+                    _ => 9,
+                },
+            }),
+            _ => Err(LevelProcessError::UnknownCompression),
+        }
+    }
+}
+
+impl Default for LevelCompression {
+    fn default() -> Self {
+        LevelCompression {
+            scheme: CompressionScheme::Gzip { operating_system: 0x0b },
+            level: 6,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -720,140 +904,168 @@ pub enum LevelProcessError {
     MissingMetadata,
 }
 
+crate::into_owned_identity!(Objects);
+
 impl ThunkProcessor for Objects {
     type Error = LevelProcessError;
     type Output<'a> = Objects;
 
     fn from_unprocessed(unprocessed: Cow<str>) -> Result<Self, LevelProcessError> {
-        // Doing the entire base64 in one go is actually faster than using base64::read::DecoderReader and
-        // having the two readers go back and forth.
-        let decoded = URL_SAFE.decode(&*unprocessed)?;
+        LazyObjects::from_encoded(&unprocessed)?.into_objects()
+    }
 
-        // Here's the deal: Robtop decompresses all levels by calling the zlib function 'inflateInit2_' with
-        // the second argument set to 47. This basically tells zlib "this data might be compressed using
-        // zlib or gzip format, with window size at most 15, but you gotta figure it out yourself".
-        // However, flate2 doesnt expose this option, so we have to manually determine whether we
-        // have gzip or zlib compression.
+    fn as_unprocessed(processed: &Objects) -> Result<Cow<str>, LevelProcessError> {
+        processed.encode(processed.compression).map(Cow::Owned)
+    }
 
-        let mut decompressed = String::new();
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
+}
 
-        match &decoded[..2] {
-            // gz magic bytes
-            [0x1f, 0x8b] => {
-                let mut decoder = GzDecoder::new(&decoded[..]);
+/// Decodes and decompresses level data as it is provided at index `4` of the level object,
+/// returning the decompressed level string and the compression that was used.
+pub(crate) fn decompress_level_data(encoded: &str) -> Result<(String, LevelCompression), LevelProcessError> {
+    // Doing the entire base64 in one go is actually faster than using base64::read::DecoderReader and
+    // having the two readers go back and forth.
+    let decoded = URL_SAFE.decode(encoded)?;
 
-                decoder.read_to_string(&mut decompressed)?;
-            },
-            // There's no such thing as "zlib magic bytes", but the first byte stores some information about how the data is compressed.
-            // '0x78' is the first byte for the compression method robtop used (note: this is only used for very old levels, as he switched
-            // to gz for newer levels)
-            [0x78, _] => {
-                let mut decoder = ZlibDecoder::new(&decoded[..]);
+    // Here's the deal: Robtop decompresses all levels by calling the zlib function 'inflateInit2_' with
+    // the second argument set to 47. This basically tells zlib "this data might be compressed using
+    // zlib or gzip format, with window size at most 15, but you gotta figure it out yourself".
+    // However, flate2 doesnt expose this option, so we have to manually determine whether we
+    // have gzip or zlib compression.
+    let compression = LevelCompression::detect(&decoded)?;
 
-                decoder.read_to_string(&mut decompressed)?;
-            },
-            _ => return Err(LevelProcessError::UnknownCompression),
-        }
+    let mut decompressed = String::new();
 
-        let mut iter = decompressed.split_terminator(';');
+    match compression.scheme {
+        CompressionScheme::Gzip { .. } => GzDecoder::new(&decoded[..]).read_to_string(&mut decompressed)?,
+        CompressionScheme::Zlib => ZlibDecoder::new(&decoded[..]).read_to_string(&mut decompressed)?,
+    };
 
-        let metadata_string = match iter.next() {
-            Some(meta) => meta,
-            None => return Err(LevelProcessError::MissingMetadata),
-        };
+    Ok((decompressed, compression))
+}
 
-        let meta = LevelMetadata::from_gj_str(metadata_string).map_err(|err| LevelProcessError::Deserialize(err.to_string()))?;
+impl Objects {
+    /// Converts these objects into level data (as provided at index `4` of the level object), using
+    /// the given compression instead of [`Objects::compression`].
+    ///
+    /// Note that the level string these objects are written as is not necessarily identical to the
+    /// one they were parsed from (for example, the order of indices might differ). Use
+    /// [`LevelCompression::compress`] on the original level string if byte-identical level data is
+    /// required.
+    pub fn encode(&self, compression: LevelCompression) -> Result<String, LevelProcessError> {
+        compression.compress(self.to_level_string()?.as_bytes())
+    }
 
-        iter.map(LevelObject::from_gj_str)
-            .collect::<Result<_, _>>()
-            .map(|objects| Objects { meta, objects })
-            .map_err(|err| LevelProcessError::Deserialize(err.to_string()))
+    /// Parses an already decompressed level string (`;`-separated objects, preceded by the metadata
+    /// section), such as the ones stored in local savefiles.
+    ///
+    /// The returned objects will be re-encoded using the default [`LevelCompression`].
+    pub fn from_level_string(level_string: &str) -> Result<Objects, LevelProcessError> {
+        parse_level_string(level_string, LevelCompression::default())
     }
 
-    fn as_unprocessed(processed: &Objects) -> Result<Cow<str>, LevelProcessError> {
+    /// Writes these objects as an uncompressed level string, the inverse of
+    /// [`Objects::from_level_string`]
+    pub fn to_level_string(&self) -> Result<String, LevelProcessError> {
         let mut bytes = Vec::new();
 
-        processed.meta.write_gj(&mut bytes)?;
+        self.meta.write_gj(&mut bytes)?;
 
         bytes.push(b';');
 
-        for object in &processed.objects {
-            object.write_gj(&mut bytes)?;
-            bytes.push(b';');
-        }
-
-        // FIXME(game specific): Should we remember the compression scheme (zlib or gz) from above, or just
-        // always re-compress using gz? Since the game dyncamially detects the compression method, we're
-        // compatible either way.
-
-        let mut encoder = GzEncoder::new(&bytes[..], Compression::new(9)); // TODO: idk what these values mean
-        let mut compressed = Vec::new();
-
-        encoder.read_to_end(&mut compressed)?;
+        write_objects(&self.objects, &mut bytes)?;
 
-        Ok(Cow::Owned(URL_SAFE.encode(compressed)))
+        Ok(String::from_utf8(bytes).unwrap())
     }
 
-    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
-        output
+    /// Calculates the duration of this level in seconds. Returns `0.0` for platformer levels, use
+    /// [`Objects::timing`] to tell those apart.
+    pub fn length_in_seconds(&self) -> f32 {
+        self.timing().map(|timing| timing.duration()).unwrap_or_default()
     }
-}
 
-impl Objects {
-    pub fn length_in_seconds(&self) -> f32 {
-        let mut portals = Vec::new();
-        let mut furthest_x = 0.0;
+    /// Calculates the timing of this level when played from the beginning.
+    ///
+    /// All speed portals are taken into account, regardless of whether they are checked, as are all
+    /// speed triggers that are not spawn triggered. The level ends at its end trigger, or at the
+    /// furthest object if it has none.
+    ///
+    /// Returns [`None`] for platformer levels, as their duration depends entirely on how they are
+    /// played.
+    pub fn timing(&self) -> Option<LevelTiming> {
+        if self.meta.platformer {
+            return None;
+        }
 
-        for object in &self.objects {
-            if let ObjectData::SpeedPortal { checked: true, speed } = object.metadata {
-                portals.push((object.x, speed))
-            }
+        Some(LevelTiming::new(0.0, self.end_x(), self.meta.starting_speed, self.speed_changes()))
+    }
 
-            furthest_x = f32::max(furthest_x, object.x);
+    /// Calculates the timing of this level when played from the given start position.
+    ///
+    /// If the given object is not a start position, the player starts at its x position with
+    /// whatever speed they would have at that point when playing the level from the beginning.
+    ///
+    /// Returns [`None`] for platformer levels.
+    pub fn timing_from(&self, start_pos: &LevelObject) -> Option<LevelTiming> {
+        if self.meta.platformer {
+            return None;
         }
 
-        portals.sort_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap());
+        let start_speed = match start_pos.metadata {
+            ObjectData::StartPos { speed } => speed,
+            _ => self.timing()?.speed_at(start_pos.x),
+        };
 
-        get_seconds_from_x_pos(furthest_x, self.meta.starting_speed, &portals)
+        Some(LevelTiming::new(start_pos.x, self.end_x(), start_speed, self.speed_changes()))
     }
-}
 
-fn get_seconds_from_x_pos(pos: f32, start_speed: Speed, portals: &[(f32, Speed)]) -> f32 {
-    let mut speed: f32 = start_speed.into();
-
-    if portals.is_empty() {
-        return pos / speed;
+    /// Builds an [`ObjectIndex`] over the objects of this level, for constant time lookups of
+    /// objects by group or color channel
+    pub fn index(&self) -> ObjectIndex<'_> {
+        ObjectIndex::new(self)
     }
 
-    let mut last_obj_pos = 0.0;
-    let mut total_time = 0.0;
-
-    for (x, portal_speed) in portals {
-        // distance between last portal and this one
-        let current_segment = x - last_obj_pos;
-
-        // break if we're past the position we want to calculate the position to
-        if pos <= current_segment {
-            break;
-        }
-
-        // Calculate time spent in this segment and add to total time
-        total_time += current_segment / speed;
+    /// All start positions placed in this level
+    pub fn start_positions(&self) -> impl Iterator<Item = &LevelObject<'static>> {
+        self.objects
+            .iter()
+            .filter(|object| matches!(object.metadata, ObjectData::StartPos { .. }))
+    }
 
-        speed = (*portal_speed).into();
+    /// The x position at which this level ends
+    fn end_x(&self) -> f32 {
+        let end_trigger = self
+            .objects
+            .iter()
+            .filter(|object| object.id == ids::END_TRIGGER)
+            .map(|object| object.x)
+            .reduce(f32::min);
 
-        last_obj_pos = *x;
+        end_trigger.unwrap_or_else(|| self.objects.iter().map(|object| object.x).fold(0.0, f32::max))
     }
 
-    // add the time spent between end and last portal to total time and return
-    (pos - last_obj_pos) / speed + total_time
+    /// All speed changes caused by speed portals and speed triggers in this level, in the order
+    /// they appear in the level string
+    ///
+    /// Spawn triggered speed triggers are not included, as the x position at which they are
+    /// activated cannot be determined without simulating the level.
+    fn speed_changes(&self) -> impl Iterator<Item = (f32, Speed)> + '_ {
+        self.objects.iter().filter_map(|object| match object.metadata {
+            ObjectData::SpeedPortal { speed, .. } => Some((object.x, speed)),
+            ObjectData::SpeedTrigger(trigger) if !trigger.activation.spawn_triggered => Some((object.x, trigger.speed)),
+            _ => None,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use base64::{engine::general_purpose::URL_SAFE, Engine};
 
-    use crate::model::level::{robtop_encode_level_password, Password};
+    use crate::model::level::{object::speed::Speed, robtop_encode_level_password, Objects, Password};
 
     #[test]
     fn deserialize_password() {
@@ -882,4 +1094,16 @@ mod tests {
         // password of 'Breakthrough' by Hinds1324
         assert_eq!(URL_SAFE.encode(&robtop_encode_level_password(0)), "AwYDBgQCBg==")
     }
+
+    #[test]
+    fn timing_with_speed_triggers() {
+        // a touch triggered fast speed trigger at 1000, a spawn triggered slow speed trigger at 2000 and a block at 3000
+        let objects = Objects::from_level_string("kA4,0;1,203,2,1000,3,15,11,1;1,200,2,2000,3,15,62,1;1,1,2,3000,3,15;").unwrap();
+        let timing = objects.timing().unwrap();
+
+        assert_eq!(timing.segments().len(), 2);
+        assert_eq!(timing.speed_at(500.0), Speed::Normal);
+        assert_eq!(timing.speed_at(2500.0), Speed::Fast);
+        assert_eq!(timing.duration(), 1000.0 / 311.58 + 2000.0 / 468.0);
+    }
 }
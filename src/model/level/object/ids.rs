@@ -1,5 +1,17 @@
+pub const START_POS: u16 = 31;
+
 pub const SLOW_PORTAL: u16 = 200;
 pub const NORMAL_PORTAL: u16 = 201;
 pub const MEDIUM_PORTAL: u16 = 202;
 pub const FAST_PORTAL: u16 = 203;
 pub const VERY_FAST_PORTAL: u16 = 1334;
+
+pub const COLOR_TRIGGER: u16 = 899;
+pub const MOVE_TRIGGER: u16 = 901;
+pub const PULSE_TRIGGER: u16 = 1006;
+pub const ALPHA_TRIGGER: u16 = 1007;
+pub const TOGGLE_TRIGGER: u16 = 1049;
+pub const SPAWN_TRIGGER: u16 = 1268;
+pub const ROTATE_TRIGGER: u16 = 1346;
+
+pub const END_TRIGGER: u16 = 3600;
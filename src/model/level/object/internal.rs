@@ -1,11 +1,20 @@
 use crate::{
-    model::level::object::{ids, speed::Speed, LevelObject, ObjectData},
-    Dash, GJFormat,
+    model::level::object::{
+        ids,
+        speed::Speed,
+        trigger::{
+            AlphaTrigger, ColorTrigger, Easing, MoveTrigger, PulseTrigger, RotateTrigger, SpawnTrigger, SpeedTrigger, ToggleTrigger,
+            TriggerActivation,
+        },
+        LevelObject, ObjectData,
+    },
+    serde::UnknownFieldsCollector,
+    Dash, GJFormat, UnknownFields,
 };
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
-pub struct InternalLevelObject {
+pub struct InternalLevelObject<'a> {
     #[serde(rename = "1")]
     id: u16,
 
@@ -24,37 +33,364 @@ pub struct InternalLevelObject {
     #[serde(rename = "6", default)]
     rotation: f32,
 
+    #[serde(rename = "21", default, skip_serializing_if = "Option::is_none")]
+    main_color_channel: Option<u16>,
+
+    #[serde(rename = "22", default, skip_serializing_if = "Option::is_none")]
+    detail_color_channel: Option<u16>,
+
+    #[serde(rename = "57", default, skip_serializing_if = "Option::is_none")]
+    groups: Option<&'a str>,
+
     // ... other common fields
 
     // portal related fields
-    #[serde(rename = "13", default)]
-    checked: bool,
+    #[serde(rename = "13", default, skip_serializing_if = "Option::is_none")]
+    checked: Option<bool>,
+
+    // start position related fields
+    #[serde(rename = "kA4", default, skip_serializing_if = "Option::is_none")]
+    start_speed: Option<u8>,
+
+    // trigger related fields. These are only written if the object actually is a trigger using them
+    #[serde(rename = "7", default, skip_serializing_if = "Option::is_none")]
+    red: Option<u8>,
+
+    #[serde(rename = "8", default, skip_serializing_if = "Option::is_none")]
+    green: Option<u8>,
+
+    #[serde(rename = "9", default, skip_serializing_if = "Option::is_none")]
+    blue: Option<u8>,
+
+    #[serde(rename = "10", default, skip_serializing_if = "Option::is_none")]
+    duration: Option<f32>,
+
+    #[serde(rename = "11", default, skip_serializing_if = "Option::is_none")]
+    touch_triggered: Option<bool>,
+
+    #[serde(rename = "14", default, skip_serializing_if = "Option::is_none")]
+    tint_ground: Option<bool>,
+
+    #[serde(rename = "15", default, skip_serializing_if = "Option::is_none")]
+    player_color_1: Option<bool>,
+
+    #[serde(rename = "16", default, skip_serializing_if = "Option::is_none")]
+    player_color_2: Option<bool>,
+
+    #[serde(rename = "17", default, skip_serializing_if = "Option::is_none")]
+    blending: Option<bool>,
+
+    #[serde(rename = "23", default, skip_serializing_if = "Option::is_none")]
+    target_channel: Option<u16>,
+
+    #[serde(rename = "28", default, skip_serializing_if = "Option::is_none")]
+    move_x: Option<f32>,
+
+    #[serde(rename = "29", default, skip_serializing_if = "Option::is_none")]
+    move_y: Option<f32>,
+
+    #[serde(rename = "30", default, skip_serializing_if = "Option::is_none")]
+    easing: Option<u8>,
+
+    #[serde(rename = "35", default, skip_serializing_if = "Option::is_none")]
+    opacity: Option<f32>,
+
+    #[serde(rename = "45", default, skip_serializing_if = "Option::is_none")]
+    fade_in: Option<f32>,
+
+    #[serde(rename = "46", default, skip_serializing_if = "Option::is_none")]
+    hold: Option<f32>,
+
+    #[serde(rename = "47", default, skip_serializing_if = "Option::is_none")]
+    fade_out: Option<f32>,
+
+    #[serde(rename = "48", default, skip_serializing_if = "Option::is_none")]
+    pulse_mode: Option<u8>,
+
+    #[serde(rename = "50", default, skip_serializing_if = "Option::is_none")]
+    copied_channel: Option<u16>,
+
+    #[serde(rename = "51", default, skip_serializing_if = "Option::is_none")]
+    target_id: Option<u16>,
+
+    #[serde(rename = "52", default, skip_serializing_if = "Option::is_none")]
+    pulse_target: Option<u8>,
+
+    #[serde(rename = "56", default, skip_serializing_if = "Option::is_none")]
+    activate_group: Option<bool>,
+
+    #[serde(rename = "58", default, skip_serializing_if = "Option::is_none")]
+    lock_to_player_x: Option<bool>,
+
+    #[serde(rename = "59", default, skip_serializing_if = "Option::is_none")]
+    lock_to_player_y: Option<bool>,
+
+    #[serde(rename = "60", default, skip_serializing_if = "Option::is_none")]
+    copy_opacity: Option<bool>,
+
+    #[serde(rename = "62", default, skip_serializing_if = "Option::is_none")]
+    spawn_triggered: Option<bool>,
+
+    #[serde(rename = "63", default, skip_serializing_if = "Option::is_none")]
+    spawn_delay: Option<f32>,
+
+    #[serde(rename = "68", default, skip_serializing_if = "Option::is_none")]
+    degrees: Option<f32>,
+
+    #[serde(rename = "69", default, skip_serializing_if = "Option::is_none")]
+    full_rotations: Option<i32>,
+
+    #[serde(rename = "70", default, skip_serializing_if = "Option::is_none")]
+    lock_object_rotation: Option<bool>,
+
+    #[serde(rename = "71", default, skip_serializing_if = "Option::is_none")]
+    center_group: Option<u16>,
+
+    #[serde(rename = "85", default, skip_serializing_if = "Option::is_none")]
+    easing_rate: Option<f32>,
+
+    #[serde(rename = "86", default, skip_serializing_if = "Option::is_none")]
+    exclusive: Option<bool>,
+
+    #[serde(rename = "87", default, skip_serializing_if = "Option::is_none")]
+    multi_trigger: Option<bool>,
+
+    // all indices not mapped to any of the above fields. These are collected outside of this struct
+    // during deserialization
+    #[serde(flatten, skip_deserializing)]
+    unknown_fields: Option<&'a UnknownFields<'a>>,
 }
 
-impl<'de> Dash<'de> for LevelObject {
+/// Invokes the given macro with all optional fields of [`InternalLevelObject`] and their indices
+macro_rules! optional_indices {
+    ($callback: ident) => {
+        $callback! {
+            checked => "13", start_speed => "kA4", red => "7", green => "8", blue => "9", duration => "10", touch_triggered => "11", tint_ground => "14",
+            player_color_1 => "15", player_color_2 => "16", blending => "17", target_channel => "23", move_x => "28", move_y => "29",
+            easing => "30", opacity => "35", fade_in => "45", hold => "46", fade_out => "47", pulse_mode => "48", copied_channel => "50",
+            target_id => "51", pulse_target => "52", activate_group => "56", lock_to_player_x => "58", lock_to_player_y => "59",
+            copy_opacity => "60", spawn_triggered => "62", spawn_delay => "63", degrees => "68", full_rotations => "69",
+            lock_object_rotation => "70", center_group => "71", easing_rate => "85", exclusive => "86", multi_trigger => "87"
+        }
+    };
+}
+
+impl<'a> InternalLevelObject<'a> {
+    fn activation(&mut self) -> TriggerActivation {
+        TriggerActivation {
+            touch_triggered: take_or_default(&mut self.touch_triggered),
+            spawn_triggered: take_or_default(&mut self.spawn_triggered),
+            multi_trigger: take_or_default(&mut self.multi_trigger),
+        }
+    }
+
+    /// Constructs the [`ObjectData`] of a speed portal with the given speed. Portals set to be
+    /// touch or spawn triggered are speed triggers instead.
+    fn speed_change(&mut self, speed: Speed) -> ObjectData {
+        let activation = self.activation();
+
+        if activation == TriggerActivation::default() {
+            ObjectData::SpeedPortal {
+                checked: take_or_default(&mut self.checked),
+                speed,
+            }
+        } else {
+            ObjectData::SpeedTrigger(SpeedTrigger { speed, activation })
+        }
+    }
+
+    fn set_activation(&mut self, activation: TriggerActivation) {
+        self.touch_triggered = non_default(activation.touch_triggered, false);
+        self.spawn_triggered = non_default(activation.spawn_triggered, false);
+        self.multi_trigger = non_default(activation.multi_trigger, false);
+    }
+
+    /// Moves all values still present in this struct that are not part of the public API (meaning
+    /// they were not taken while constructing the [`ObjectData`]) into the given [`UnknownFields`]
+    fn preserve_unconsumed(self, unknown_fields: &mut UnknownFields) {
+        macro_rules! preserve {
+            ($($field: ident => $index: literal),*) => {
+                $(
+                    if let Some(value) = self.$field {
+                        unknown_fields.insert($index, value.to_robtop_string());
+                    }
+                )*
+            };
+        }
+
+        optional_indices!(preserve);
+    }
+
+    /// The indices of all optional values set in this struct
+    fn set_indices(&self) -> Vec<&'static str> {
+        let mut indices = Vec::new();
+
+        macro_rules! collect {
+            ($($field: ident => $index: literal),*) => {
+                $(
+                    if self.$field.is_some() {
+                        indices.push($index);
+                    }
+                )*
+            };
+        }
+
+        optional_indices!(collect);
+
+        indices
+    }
+}
+
+/// Takes the value out of `field`, unless it is equal to `default`
+///
+/// Values explicitly set to their default are left in place, so that
+/// [`InternalLevelObject::preserve_unconsumed`] moves them into the [`UnknownFields`]. This way,
+/// they are written back out even though serialization omits default values.
+fn take_or<T: PartialEq + Copy>(field: &mut Option<T>, default: T) -> T {
+    match *field {
+        Some(value) if value != default => {
+            *field = None;
+            value
+        },
+        _ => default,
+    }
+}
+
+fn take_or_default<T: PartialEq + Copy + Default>(field: &mut Option<T>) -> T {
+    take_or(field, T::default())
+}
+
+/// Returns [`None`] if `value` is equal to `default`, so that the corresponding index is omitted
+fn non_default<T: PartialEq>(value: T, default: T) -> Option<T> {
+    if value == default {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Parses the `.`-separated list of group IDs stored at index `57`
+fn parse_groups(groups: &str) -> Result<Vec<u16>, std::num::ParseIntError> {
+    groups.split('.').filter(|group| !group.is_empty()).map(str::parse).collect()
+}
+
+trait ToRobtopString {
+    fn to_robtop_string(&self) -> String;
+}
+
+impl ToRobtopString for bool {
+    fn to_robtop_string(&self) -> String {
+        if *self { "1" } else { "0" }.to_owned()
+    }
+}
+
+macro_rules! display_to_robtop_string {
+    ($($t: ty),*) => {
+        $(
+            impl ToRobtopString for $t {
+                fn to_robtop_string(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+display_to_robtop_string!(u8, u16, i32, f32);
+
+impl<'de> Dash<'de> for LevelObject<'de> {
     fn dash_deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let internal = InternalLevelObject::deserialize(deserializer)?;
+        let mut unknown_fields = UnknownFields::new();
+        let mut internal = InternalLevelObject::deserialize(UnknownFieldsCollector::new(deserializer, &mut unknown_fields))?;
 
         let metadata = match internal.id {
-            ids::SLOW_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
-                speed: Speed::Slow,
-            },
-            ids::NORMAL_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
-                speed: Speed::Normal,
-            },
-            ids::FAST_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
-                speed: Speed::Fast,
-            },
-            ids::VERY_FAST_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
-                speed: Speed::VeryFast,
+            ids::SLOW_PORTAL => internal.speed_change(Speed::Slow),
+            ids::NORMAL_PORTAL => internal.speed_change(Speed::Normal),
+            ids::MEDIUM_PORTAL => internal.speed_change(Speed::Medium),
+            ids::FAST_PORTAL => internal.speed_change(Speed::Fast),
+            ids::VERY_FAST_PORTAL => internal.speed_change(Speed::VeryFast),
+            ids::START_POS => ObjectData::StartPos {
+                speed: Speed::from(take_or_default(&mut internal.start_speed)),
             },
+            ids::MOVE_TRIGGER => ObjectData::MoveTrigger(MoveTrigger {
+                target_group: take_or_default(&mut internal.target_id),
+                duration: take_or_default(&mut internal.duration),
+                easing: Easing::from(take_or_default(&mut internal.easing)),
+                easing_rate: take_or_default(&mut internal.easing_rate),
+                offset_x: take_or_default(&mut internal.move_x),
+                offset_y: take_or_default(&mut internal.move_y),
+                lock_to_player_x: take_or_default(&mut internal.lock_to_player_x),
+                lock_to_player_y: take_or_default(&mut internal.lock_to_player_y),
+                activation: internal.activation(),
+            }),
+            ids::ROTATE_TRIGGER => ObjectData::RotateTrigger(RotateTrigger {
+                target_group: take_or_default(&mut internal.target_id),
+                center_group: take_or_default(&mut internal.center_group),
+                duration: take_or_default(&mut internal.duration),
+                easing: Easing::from(take_or_default(&mut internal.easing)),
+                easing_rate: take_or_default(&mut internal.easing_rate),
+                degrees: take_or_default(&mut internal.degrees),
+                full_rotations: take_or_default(&mut internal.full_rotations),
+                lock_object_rotation: take_or_default(&mut internal.lock_object_rotation),
+                activation: internal.activation(),
+            }),
+            ids::ALPHA_TRIGGER => ObjectData::AlphaTrigger(AlphaTrigger {
+                target_group: take_or_default(&mut internal.target_id),
+                duration: take_or_default(&mut internal.duration),
+                opacity: take_or(&mut internal.opacity, 1.0),
+                activation: internal.activation(),
+            }),
+            ids::TOGGLE_TRIGGER => ObjectData::ToggleTrigger(ToggleTrigger {
+                target_group: take_or_default(&mut internal.target_id),
+                activate_group: take_or_default(&mut internal.activate_group),
+                activation: internal.activation(),
+            }),
+            ids::SPAWN_TRIGGER => ObjectData::SpawnTrigger(SpawnTrigger {
+                target_group: take_or_default(&mut internal.target_id),
+                delay: take_or_default(&mut internal.spawn_delay),
+                activation: internal.activation(),
+            }),
+            ids::PULSE_TRIGGER => ObjectData::PulseTrigger(PulseTrigger {
+                target_id: take_or_default(&mut internal.target_id),
+                target_type: take_or_default(&mut internal.pulse_target).into(),
+                fade_in: take_or_default(&mut internal.fade_in),
+                hold: take_or_default(&mut internal.hold),
+                fade_out: take_or_default(&mut internal.fade_out),
+                mode: take_or_default(&mut internal.pulse_mode).into(),
+                red: take_or_default(&mut internal.red),
+                green: take_or_default(&mut internal.green),
+                blue: take_or_default(&mut internal.blue),
+                copied_channel: take_or_default(&mut internal.copied_channel),
+                exclusive: take_or_default(&mut internal.exclusive),
+                activation: internal.activation(),
+            }),
+            ids::COLOR_TRIGGER => ObjectData::ColorTrigger(ColorTrigger {
+                target_channel: take_or_default(&mut internal.target_channel),
+                duration: take_or_default(&mut internal.duration),
+                red: take_or_default(&mut internal.red),
+                green: take_or_default(&mut internal.green),
+                blue: take_or_default(&mut internal.blue),
+                opacity: take_or(&mut internal.opacity, 1.0),
+                blending: take_or_default(&mut internal.blending),
+                player_color_1: take_or_default(&mut internal.player_color_1),
+                player_color_2: take_or_default(&mut internal.player_color_2),
+                copied_channel: take_or_default(&mut internal.copied_channel),
+                copy_opacity: take_or_default(&mut internal.copy_opacity),
+                tint_ground: take_or_default(&mut internal.tint_ground),
+                activation: internal.activation(),
+            }),
             _ => ObjectData::Unknown,
         };
 
+        let groups = match internal.groups {
+            Some(groups) => parse_groups(groups).map_err(serde::de::Error::custom)?,
+            None => Vec::new(),
+        };
+
+        // Trigger related indices can also appear on objects that do not use them (or on objects we have
+        // not modelled yet). Do not lose them.
+        internal.preserve_unconsumed(&mut unknown_fields);
+
         Ok(LevelObject {
             id: internal.id,
             x: internal.x,
@@ -62,11 +398,18 @@ impl<'de> Dash<'de> for LevelObject {
             flipped_x: internal.flipped_x,
             flipped_y: internal.flipped_y,
             rotation: internal.rotation,
+            groups,
+            main_color_channel: internal.main_color_channel,
+            detail_color_channel: internal.detail_color_channel,
             metadata,
+            unknown_fields,
         })
     }
 
     fn dash_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let unknown_fields;
+        let groups = self.groups.iter().map(u16::to_string).collect::<Vec<_>>().join(".");
+
         let mut internal = InternalLevelObject {
             id: self.id,
             x: self.x,
@@ -74,21 +417,113 @@ impl<'de> Dash<'de> for LevelObject {
             flipped_x: self.flipped_x,
             flipped_y: self.flipped_y,
             rotation: self.rotation,
+            main_color_channel: self.main_color_channel,
+            detail_color_channel: self.detail_color_channel,
+            groups: if groups.is_empty() { None } else { Some(&groups) },
+            unknown_fields: Some(&self.unknown_fields),
             ..InternalLevelObject::default()
         };
 
         match self.metadata {
             ObjectData::None | ObjectData::Unknown => {},
             ObjectData::SpeedPortal { checked, .. } => {
-                internal.checked = checked;
+                internal.checked = non_default(checked, false);
+            },
+            ObjectData::SpeedTrigger(trigger) => {
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::StartPos { speed } => {
+                internal.start_speed = non_default(speed.into(), 0);
+            },
+            ObjectData::MoveTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_group, 0);
+                internal.duration = non_default(trigger.duration, 0.0);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.move_x = non_default(trigger.offset_x, 0.0);
+                internal.move_y = non_default(trigger.offset_y, 0.0);
+                internal.lock_to_player_x = non_default(trigger.lock_to_player_x, false);
+                internal.lock_to_player_y = non_default(trigger.lock_to_player_y, false);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::RotateTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_group, 0);
+                internal.center_group = non_default(trigger.center_group, 0);
+                internal.duration = non_default(trigger.duration, 0.0);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.degrees = non_default(trigger.degrees, 0.0);
+                internal.full_rotations = non_default(trigger.full_rotations, 0);
+                internal.lock_object_rotation = non_default(trigger.lock_object_rotation, false);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::AlphaTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_group, 0);
+                internal.duration = non_default(trigger.duration, 0.0);
+                internal.opacity = non_default(trigger.opacity, 1.0);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::ToggleTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_group, 0);
+                internal.activate_group = non_default(trigger.activate_group, false);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::SpawnTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_group, 0);
+                internal.spawn_delay = non_default(trigger.delay, 0.0);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::PulseTrigger(trigger) => {
+                internal.target_id = non_default(trigger.target_id, 0);
+                internal.pulse_target = non_default(trigger.target_type.into(), 0);
+                internal.fade_in = non_default(trigger.fade_in, 0.0);
+                internal.hold = non_default(trigger.hold, 0.0);
+                internal.fade_out = non_default(trigger.fade_out, 0.0);
+                internal.pulse_mode = non_default(trigger.mode.into(), 0);
+                internal.red = non_default(trigger.red, 0);
+                internal.green = non_default(trigger.green, 0);
+                internal.blue = non_default(trigger.blue, 0);
+                internal.copied_channel = non_default(trigger.copied_channel, 0);
+                internal.exclusive = non_default(trigger.exclusive, false);
+                internal.set_activation(trigger.activation);
+            },
+            ObjectData::ColorTrigger(trigger) => {
+                internal.target_channel = non_default(trigger.target_channel, 0);
+                internal.duration = non_default(trigger.duration, 0.0);
+                internal.red = non_default(trigger.red, 0);
+                internal.green = non_default(trigger.green, 0);
+                internal.blue = non_default(trigger.blue, 0);
+                internal.opacity = non_default(trigger.opacity, 1.0);
+                internal.blending = non_default(trigger.blending, false);
+                internal.player_color_1 = non_default(trigger.player_color_1, false);
+                internal.player_color_2 = non_default(trigger.player_color_2, false);
+                internal.copied_channel = non_default(trigger.copied_channel, 0);
+                internal.copy_opacity = non_default(trigger.copy_opacity, false);
+                internal.tint_ground = non_default(trigger.tint_ground, false);
+                internal.set_activation(trigger.activation);
             },
         };
 
+        // Values explicitly set to their default were kept in the unknown fields during
+        // deserialization. If they have been changed since, the modelled value takes precedence.
+        let set_indices = internal.set_indices();
+
+        if set_indices.iter().any(|index| self.unknown_fields.get(index).is_some()) {
+            let mut remaining = self.unknown_fields.clone();
+
+            for index in set_indices {
+                remaining.remove(index);
+            }
+
+            unknown_fields = remaining;
+            internal.unknown_fields = Some(&unknown_fields);
+        }
+
         internal.serialize(serializer)
     }
 }
 
-impl<'de> GJFormat<'de> for LevelObject {
+impl<'de> GJFormat<'de> for LevelObject<'de> {
     const DELIMITER: &'static str = ",";
     const MAP_LIKE: bool = true;
 }
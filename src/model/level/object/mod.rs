@@ -1,20 +1,53 @@
-use crate::model::level::object::speed::Speed;
+use crate::model::level::object::{
+    speed::Speed,
+    trigger::{
+        AlphaTrigger, ColorTrigger, MoveTrigger, PulseTarget, PulseTrigger, RotateTrigger, SpawnTrigger, SpeedTrigger, ToggleTrigger,
+    },
+};
+use crate::{
+    model::level::{lazy::RawObjects, LevelProcessError},
+    GJFormat, UnknownFields,
+};
+use dash_rs_derive::IntoOwned;
 use serde::{Deserialize, Serialize};
+use variant_partial_eq::VariantPartialEq;
 
 pub mod ids;
 mod internal;
 pub mod speed;
+pub mod trigger;
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-pub struct LevelObject {
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, IntoOwned)]
+pub struct LevelObject<'a> {
     pub id: u16,
     pub x: f32,
     pub y: f32,
     pub flipped_x: bool,
     pub flipped_y: bool,
     pub rotation: f32,
+    /// The IDs of the groups this object is part of
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `57`, as a `.`-separated list
+    pub groups: Vec<u16>,
+    /// The color channel this object's main color is taken from, or [`None`] if the object uses
+    /// its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `21`
+    pub main_color_channel: Option<u16>,
+    /// The color channel this object's detail color is taken from, or [`None`] if the object uses
+    /// its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `22`
+    pub detail_color_channel: Option<u16>,
     // ... other fields they all have ...
     pub metadata: ObjectData,
+    /// All indices of this object not mapped to any of the above fields (or to the fields of its
+    /// [`ObjectData`]). This includes indices of the [`ObjectData`] that were explicitly set to
+    /// their default value, as these are otherwise omitted when writing the object.
+    pub unknown_fields: UnknownFields<'a>,
 }
 
 #[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
@@ -22,4 +55,203 @@ pub enum ObjectData {
     None,
     Unknown,
     SpeedPortal { checked: bool, speed: Speed },
+    SpeedTrigger(SpeedTrigger),
+    StartPos { speed: Speed },
+    MoveTrigger(MoveTrigger),
+    RotateTrigger(RotateTrigger),
+    AlphaTrigger(AlphaTrigger),
+    ToggleTrigger(ToggleTrigger),
+    SpawnTrigger(SpawnTrigger),
+    PulseTrigger(PulseTrigger),
+    ColorTrigger(ColorTrigger),
+}
+
+/// Parses a `;`-separated list of objects without metadata section, such as the ones the level
+/// editor copies to the clipboard
+pub fn parse_object_string(object_string: &str) -> Result<Vec<LevelObject<'_>>, LevelProcessError> {
+    RawObjects::new(object_string).map(|object| object.parse()).collect()
+}
+
+/// Writes the given objects as a `;`-separated list without metadata section, the inverse of
+/// [`parse_object_string`]
+pub fn write_object_string(objects: &[LevelObject]) -> Result<String, LevelProcessError> {
+    let mut bytes = Vec::new();
+
+    write_objects(objects, &mut bytes)?;
+
+    Ok(String::from_utf8(bytes).unwrap())
+}
+
+pub(crate) fn write_objects(objects: &[LevelObject], bytes: &mut Vec<u8>) -> Result<(), LevelProcessError> {
+    for object in objects {
+        object.write_gj(&mut *bytes)?;
+        bytes.push(b';');
+    }
+
+    Ok(())
+}
+
+impl ObjectData {
+    /// The group targeted by this object, if it is a trigger acting on a group
+    pub fn target_group(&self) -> Option<u16> {
+        match self {
+            ObjectData::MoveTrigger(trigger) => Some(trigger.target_group),
+            ObjectData::RotateTrigger(trigger) => Some(trigger.target_group),
+            ObjectData::AlphaTrigger(trigger) => Some(trigger.target_group),
+            ObjectData::ToggleTrigger(trigger) => Some(trigger.target_group),
+            ObjectData::SpawnTrigger(trigger) => Some(trigger.target_group),
+            ObjectData::PulseTrigger(trigger) if trigger.target_type == PulseTarget::Group => Some(trigger.target_id),
+            _ => None,
+        }
+    }
+
+    /// The color channel targeted by this object, if it is a trigger acting on a color channel
+    pub fn target_color_channel(&self) -> Option<u16> {
+        match self {
+            ObjectData::ColorTrigger(trigger) => Some(trigger.target_channel),
+            ObjectData::PulseTrigger(trigger) if trigger.target_type == PulseTarget::ColorChannel => Some(trigger.target_id),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::object::{
+            ids, parse_object_string,
+            speed::Speed,
+            trigger::{Easing, MoveTrigger, SpeedTrigger, TriggerActivation},
+            write_object_string, LevelObject, ObjectData,
+        },
+        GJFormat,
+    };
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn deserialize_move_trigger() {
+        let object = LevelObject::from_gj_str("1,901,2,105,3,75,10,0.5,28,30,29,-10,30,2,51,7,58,1,85,2,87,1").unwrap();
+
+        assert_eq!(object.id, ids::MOVE_TRIGGER);
+        assert_eq!(
+            object.metadata,
+            ObjectData::MoveTrigger(MoveTrigger {
+                target_group: 7,
+                duration: 0.5,
+                easing: Easing::EaseIn,
+                easing_rate: 2.0,
+                offset_x: 30.0,
+                offset_y: -10.0,
+                lock_to_player_x: true,
+                lock_to_player_y: false,
+                activation: TriggerActivation {
+                    touch_triggered: false,
+                    spawn_triggered: false,
+                    multi_trigger: true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn trigger_round_trip() {
+        for raw in [
+            "1,901,2,105,3,75,10,0.5,28,30,29,-10,30,2,51,7,58,1,85,2,87,1",
+            "1,1346,2,15,3,15,10,1,51,3,68,90,69,2,70,1,71,4",
+            "1,1007,2,15,3,15,10,2,35,0.25,51,5,11,1",
+            "1,1049,2,15,3,15,51,12,56,1,62,1",
+            "1,1268,2,15,3,15,51,6,63,1.5",
+            "1,1006,2,15,3,15,7,255,8,0,9,128,45,0.1,46,0.5,47,0.2,48,0,51,9,52,1,86,1",
+            "1,899,2,15,3,15,7,255,8,255,9,0,10,0.5,17,1,23,1004,35,0.75",
+            "1,203,2,15,3,15,11,1,87,1",
+        ] {
+            let object = LevelObject::from_gj_str(raw).unwrap();
+            let mut written = Vec::new();
+
+            object.write_gj(&mut written).unwrap();
+
+            let written = String::from_utf8(written).unwrap();
+            let reparsed = LevelObject::from_gj_str(&written).unwrap();
+
+            assert_eq!(object, reparsed, "{} was written as {}", raw, written);
+
+            // Apart from the always-written common indices `4`, `5` and `6`, exactly the indices present in
+            // the input are written (including ones explicitly set to their default value)
+            let indices = |object: &str| object.split(',').step_by(2).map(str::to_owned).collect::<BTreeSet<_>>();
+            let mut expected = indices(raw);
+
+            expected.extend(["4", "5", "6"].map(str::to_owned));
+
+            assert_eq!(indices(&written), expected, "{} was written as {}", raw, written);
+        }
+    }
+
+    #[test]
+    fn deserialize_start_pos() {
+        let object = LevelObject::from_gj_str("1,31,2,1215,3,105,kA2,1,kA3,0,kA4,2").unwrap();
+
+        assert_eq!(object.metadata, ObjectData::StartPos { speed: Speed::Medium });
+        assert_eq!(object.unknown_fields.get("kA2"), Some("1"));
+
+        let portal = LevelObject::from_gj_str("1,202,2,1500,3,105,13,1").unwrap();
+
+        assert_eq!(
+            portal.metadata,
+            ObjectData::SpeedPortal {
+                checked: true,
+                speed: Speed::Medium
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_speed_trigger() {
+        let object = LevelObject::from_gj_str("1,1334,2,1500,3,105,62,1").unwrap();
+
+        assert_eq!(
+            object.metadata,
+            ObjectData::SpeedTrigger(SpeedTrigger {
+                speed: Speed::VeryFast,
+                activation: TriggerActivation {
+                    touch_triggered: false,
+                    spawn_triggered: true,
+                    multi_trigger: false,
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn object_string_round_trip() {
+        let raw = "1,1,2,15,3,15;1,901,2,45,3,15,51,3;";
+        let objects = parse_object_string(raw).unwrap();
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[1].id, ids::MOVE_TRIGGER);
+        assert_eq!(parse_object_string(&write_object_string(&objects).unwrap()).unwrap(), objects);
+        assert_eq!(parse_object_string("").unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn preserve_unknown_fields() {
+        // a block in group 2 and 3 using color channel 1004, with an (unused) duration set
+        let object = LevelObject::from_gj_str("1,1,2,15,3,15,21,1004,10,0.5,57,2.3,155,1").unwrap();
+
+        assert_eq!(object.metadata, ObjectData::Unknown);
+        assert_eq!(object.groups, vec![2, 3]);
+        assert_eq!(object.main_color_channel, Some(1004));
+        assert_eq!(object.detail_color_channel, None);
+        assert_eq!(object.unknown_fields.get("155"), Some("1"));
+        assert_eq!(object.unknown_fields.get("10"), Some("0.5"));
+        assert_eq!(object.unknown_fields.len(), 2);
+
+        let mut written = Vec::new();
+
+        object.write_gj(&mut written).unwrap();
+
+        let written = String::from_utf8(written).unwrap();
+        let reparsed = LevelObject::from_gj_str(&written).unwrap();
+
+        assert_eq!(object, reparsed);
+    }
 }
@@ -1,14 +1,25 @@
 // TODO: Speed portals and stuff
+use dash_rs_derive::Dash;
 use serde::{Deserialize, Serialize};
 
 /// Enum modelling the different speeds a player can have during gameplay
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+///
+/// ## GD Internals:
+/// The values of the variants are the game-internal speed indices, as used by the level's starting
+/// speed and by start positions. Note that the game orders these indices differently from the
+/// speed portals themselves, with normal speed being `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Dash)]
 pub enum Speed {
+    #[dash(value = 1)]
     Slow,
     #[default]
+    #[dash(value = 0)]
     Normal,
+    #[dash(value = 2)]
     Medium,
+    #[dash(value = 3)]
     Fast,
+    #[dash(value = 4)]
     VeryFast,
     Unknown(u8),
 }
@@ -27,31 +38,3 @@ impl From<Speed> for f32 {
         }
     }
 }
-
-impl From<u8> for Speed {
-    fn from(value: u8) -> Self {
-        match value {
-            0 => Speed::Slow,
-            1 => Speed::Normal,
-            2 => Speed::Medium,
-            3 => Speed::Fast,
-            4 => Speed::VeryFast,
-            unknown => Speed::Unknown(unknown),
-        }
-    }
-}
-
-impl From<Speed> for u8 {
-    fn from(speed: Speed) -> Self {
-        match speed {
-            Speed::Slow => 0,
-            Speed::Normal => 1,
-            Speed::Medium => 2,
-            Speed::Fast => 3,
-            Speed::VeryFast => 4,
-            Speed::Unknown(unknown) => unknown,
-        }
-    }
-}
-
-crate::into_conversion!(Speed, u8);
@@ -0,0 +1,531 @@
+//! Module containing the typed representations of Geometry Dash's trigger objects
+//!
+//! Each trigger stores its settings in object keys that are shared between all trigger types (for
+//! instance, the target group is always stored at key `51`). The documentation on each field lists
+//! the key it is read from.
+
+use crate::model::level::object::speed::Speed;
+use dash_rs_derive::Dash;
+use serde::{Deserialize, Serialize};
+
+/// Enum modelling the different easing functions a trigger can use for its animation
+///
+/// ## GD Internals:
+/// The easing is stored at key `30` of trigger objects, using the value listed on each variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, Dash)]
+pub enum Easing {
+    /// No easing, the animation progresses linearly
+    ///
+    /// This variant is represented by the value `0`
+    #[default]
+    #[dash(value = 0)]
+    None,
+
+    /// This variant is represented by the value `1`
+    #[dash(value = 1)]
+    EaseInOut,
+
+    /// This variant is represented by the value `2`
+    #[dash(value = 2)]
+    EaseIn,
+
+    /// This variant is represented by the value `3`
+    #[dash(value = 3)]
+    EaseOut,
+
+    /// This variant is represented by the value `4`
+    #[dash(value = 4)]
+    ElasticInOut,
+
+    /// This variant is represented by the value `5`
+    #[dash(value = 5)]
+    ElasticIn,
+
+    /// This variant is represented by the value `6`
+    #[dash(value = 6)]
+    ElasticOut,
+
+    /// This variant is represented by the value `7`
+    #[dash(value = 7)]
+    BounceInOut,
+
+    /// This variant is represented by the value `8`
+    #[dash(value = 8)]
+    BounceIn,
+
+    /// This variant is represented by the value `9`
+    #[dash(value = 9)]
+    BounceOut,
+
+    /// This variant is represented by the value `10`
+    #[dash(value = 10)]
+    ExponentialInOut,
+
+    /// This variant is represented by the value `11`
+    #[dash(value = 11)]
+    ExponentialIn,
+
+    /// This variant is represented by the value `12`
+    #[dash(value = 12)]
+    ExponentialOut,
+
+    /// This variant is represented by the value `13`
+    #[dash(value = 13)]
+    SineInOut,
+
+    /// This variant is represented by the value `14`
+    #[dash(value = 14)]
+    SineIn,
+
+    /// This variant is represented by the value `15`
+    #[dash(value = 15)]
+    SineOut,
+
+    /// This variant is represented by the value `16`
+    #[dash(value = 16)]
+    BackInOut,
+
+    /// This variant is represented by the value `17`
+    #[dash(value = 17)]
+    BackIn,
+
+    /// This variant is represented by the value `18`
+    #[dash(value = 18)]
+    BackOut,
+
+    /// Easing function not yet known to dash-rs
+    Unknown(u8),
+}
+
+/// Struct describing how a trigger gets activated
+///
+/// By default, triggers activate once the player passes their x position
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct TriggerActivation {
+    /// Whether this trigger is only activated when the player touches it
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `11`
+    pub touch_triggered: bool,
+
+    /// Whether this trigger is only activated by a spawn trigger targeting one of its groups
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `62`
+    pub spawn_triggered: bool,
+
+    /// Whether this trigger can be activated multiple times. Only meaningful for touch and spawn
+    /// triggered triggers.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `87`
+    pub multi_trigger: bool,
+}
+
+/// A speed portal set to be touch or spawn triggered, which changes the player's speed once
+/// activated
+///
+/// ## GD Internals:
+/// Since Geometry Dash 2.2, speed portals can be turned into triggers by setting them to be touch
+/// or spawn triggered. These share their object IDs with regular speed portals, meaning the speed
+/// is determined by the object ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpeedTrigger {
+    /// The speed the player moves at after this trigger was activated
+    pub speed: Speed,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// A move trigger, which moves all objects of a group by some offset
+///
+/// ## GD Internals:
+/// Move triggers have object ID `901`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct MoveTrigger {
+    /// The group whose objects are moved
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_group: u16,
+
+    /// The duration of the movement, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `10`
+    pub duration: f32,
+
+    /// The easing function used for the movement
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `30`
+    pub easing: Easing,
+
+    /// The rate of the easing function. Only meaningful for some easings
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `85`
+    pub easing_rate: f32,
+
+    /// The offset to move the group by in x direction, in the units used by the in-game editor
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `28`
+    pub offset_x: f32,
+
+    /// The offset to move the group by in y direction, in the units used by the in-game editor
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `29`
+    pub offset_y: f32,
+
+    /// Whether the group follows the player's x movement instead of moving by `offset_x`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `58`
+    pub lock_to_player_x: bool,
+
+    /// Whether the group follows the player's y movement instead of moving by `offset_y`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `59`
+    pub lock_to_player_y: bool,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// A rotate trigger, which rotates all objects of a group around the objects of another group
+///
+/// ## GD Internals:
+/// Rotate triggers have object ID `1346`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct RotateTrigger {
+    /// The group whose objects are rotated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_group: u16,
+
+    /// The group whose objects form the center of the rotation
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `71`
+    pub center_group: u16,
+
+    /// The duration of the rotation, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `10`
+    pub duration: f32,
+
+    /// The easing function used for the rotation
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `30`
+    pub easing: Easing,
+
+    /// The rate of the easing function. Only meaningful for some easings
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `85`
+    pub easing_rate: f32,
+
+    /// The amount of degrees to rotate by, in addition to [`RotateTrigger::full_rotations`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `68`
+    pub degrees: f32,
+
+    /// The amount of full 360° rotations to perform
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `69`
+    pub full_rotations: i32,
+
+    /// Whether the rotated objects keep their own orientation while moving around the center
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `70`
+    pub lock_object_rotation: bool,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// An alpha trigger, which changes the opacity of all objects of a group
+///
+/// ## GD Internals:
+/// Alpha triggers have object ID `1007`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct AlphaTrigger {
+    /// The group whose opacity is changed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_group: u16,
+
+    /// The duration of the fade, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `10`
+    pub duration: f32,
+
+    /// The opacity to fade to, between `0.0` and `1.0`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `35`
+    pub opacity: f32,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// A toggle trigger, which enables or disables all objects of a group
+///
+/// ## GD Internals:
+/// Toggle triggers have object ID `1049`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub struct ToggleTrigger {
+    /// The group which is toggled
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_group: u16,
+
+    /// Whether the group is enabled (`true`) or disabled (`false`)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `56`
+    pub activate_group: bool,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// A spawn trigger, which activates all spawn triggered triggers in a group
+///
+/// ## GD Internals:
+/// Spawn triggers have object ID `1268`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct SpawnTrigger {
+    /// The group whose triggers are activated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_group: u16,
+
+    /// The delay before the group is spawned, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `63`
+    pub delay: f32,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// Enum describing what kind of target a [`PulseTrigger`] pulses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, Dash)]
+pub enum PulseTarget {
+    /// The pulse target is a color channel
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0`
+    #[default]
+    #[dash(value = 0)]
+    ColorChannel,
+
+    /// The pulse target is a group
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1`
+    #[dash(value = 1)]
+    Group,
+
+    /// Pulse target not yet known to dash-rs
+    Unknown(u8),
+}
+
+/// Enum describing which color a [`PulseTrigger`] pulses to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, Dash)]
+pub enum PulseMode {
+    /// The target pulses to the RGB color set in the trigger
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0`
+    #[default]
+    #[dash(value = 0)]
+    Color,
+
+    /// The target pulses to the HSV-modified color of another color channel
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1`
+    #[dash(value = 1)]
+    Hsv,
+
+    /// Pulse mode not yet known to dash-rs
+    Unknown(u8),
+}
+
+/// A pulse trigger, which briefly changes the color of a color channel or group
+///
+/// ## GD Internals:
+/// Pulse triggers have object ID `1006`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct PulseTrigger {
+    /// The ID of the color channel or group (depending on [`PulseTrigger::target_type`]) to pulse
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `51`
+    pub target_id: u16,
+
+    /// Whether [`PulseTrigger::target_id`] refers to a color channel or a group
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `52`
+    pub target_type: PulseTarget,
+
+    /// The time it takes to fade to the pulse color, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `45`
+    pub fade_in: f32,
+
+    /// The time the pulse color is held, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `46`
+    pub hold: f32,
+
+    /// The time it takes to fade back to the original color, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `47`
+    pub fade_out: f32,
+
+    /// Whether to pulse to the RGB color set in this trigger, or to the color of another channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `48`
+    pub mode: PulseMode,
+
+    /// The red component of the pulse color. Only meaningful in [`PulseMode::Color`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `7`
+    pub red: u8,
+
+    /// The green component of the pulse color. Only meaningful in [`PulseMode::Color`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `8`
+    pub green: u8,
+
+    /// The blue component of the pulse color. Only meaningful in [`PulseMode::Color`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `9`
+    pub blue: u8,
+
+    /// The color channel whose color to pulse to. Only meaningful in [`PulseMode::Hsv`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `50`
+    pub copied_channel: u16,
+
+    /// Whether this pulse overrides all other pulses currently active on the same target
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `86`
+    pub exclusive: bool,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
+
+/// A color trigger, which changes the color of a color channel
+///
+/// ## GD Internals:
+/// Color triggers have object ID `899`. The pre-2.0 color triggers with a fixed target channel are
+/// not modelled by this struct.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct ColorTrigger {
+    /// The color channel whose color is changed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `23`
+    pub target_channel: u16,
+
+    /// The duration of the color transition, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `10`
+    pub duration: f32,
+
+    /// The red component of the new color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `7`
+    pub red: u8,
+
+    /// The green component of the new color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `8`
+    pub green: u8,
+
+    /// The blue component of the new color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `9`
+    pub blue: u8,
+
+    /// The opacity of the new color, between `0.0` and `1.0`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `35`
+    pub opacity: f32,
+
+    /// Whether the color channel uses additive blending
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `17`
+    pub blending: bool,
+
+    /// Whether the color channel is set to the player's primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `15`
+    pub player_color_1: bool,
+
+    /// Whether the color channel is set to the player's secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `16`
+    pub player_color_2: bool,
+
+    /// The color channel whose color is copied, or `0` if no color is copied
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `50`
+    pub copied_channel: u16,
+
+    /// Whether the opacity of the copied channel is copied as well
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `60`
+    pub copy_opacity: bool,
+
+    /// Whether the ground is tinted along with the background. Only meaningful for the background
+    /// color channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `14`
+    pub tint_ground: bool,
+
+    /// How this trigger is activated
+    pub activation: TriggerActivation,
+}
@@ -0,0 +1,288 @@
+//! Module containing a spatial index over the objects of a level, allowing efficient queries for
+//! all objects in some region of the level
+
+use crate::model::level::{object::LevelObject, Objects};
+use std::collections::HashMap;
+
+/// The side length of a single grid square in the level editor
+const GRID_SIZE: f32 = 30.0;
+
+/// The side length of the buckets objects are sorted into
+const CELL_SIZE: f32 = 4.0 * GRID_SIZE;
+
+/// An axis-aligned rectangle in level coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub max_x: f32,
+    pub max_y: f32,
+}
+
+impl Rect {
+    /// Constructs the rectangle spanned by the given two corners
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Rect {
+        Rect {
+            min_x: x1.min(x2),
+            min_y: y1.min(y2),
+            max_x: x1.max(x2),
+            max_y: y1.max(y2),
+        }
+    }
+
+    /// Whether this rectangle and the given one overlap (touching edges count as overlapping)
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min_x <= other.max_x && other.min_x <= self.max_x && self.min_y <= other.max_y && other.min_y <= self.max_y
+    }
+
+    /// The smallest rectangle containing both this rectangle and the given one
+    fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// The overlap between this rectangle and the given one, if there is any
+    fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(Rect {
+            min_x: self.min_x.max(other.min_x),
+            min_y: self.min_y.max(other.min_y),
+            max_x: self.max_x.min(other.max_x),
+            max_y: self.max_y.min(other.max_y),
+        })
+    }
+}
+
+/// Grid based spatial index over the objects of a level.
+///
+/// Each object is assigned a rectangular footprint centered on its position, which is rotated by
+/// the object's rotation. Objects are then found by any query whose region overlaps the axis-aligned
+/// bounding box of that footprint. As footprints are centered on the object's position, flipping an
+/// object does not change the region it occupies.
+///
+/// The index borrows the [`Objects`] it was built from and does not modify them. All queries return
+/// the matching objects in the order they appear in the level (except for [`SpatialIndex::near`]).
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<'a> {
+    objects: &'a [LevelObject<'static>],
+    bounds: Vec<Rect>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    extent: Option<Rect>,
+}
+
+impl<'a> SpatialIndex<'a> {
+    /// Builds a spatial index assuming every object occupies a single grid square (30x30 units)
+    pub fn new(objects: &'a Objects) -> SpatialIndex<'a> {
+        SpatialIndex::with_footprint(objects, |_| (GRID_SIZE, GRID_SIZE))
+    }
+
+    /// Builds a spatial index using the given function to determine the (unrotated) width and
+    /// height of each object
+    pub fn with_footprint(objects: &'a Objects, footprint: impl Fn(&LevelObject) -> (f32, f32)) -> SpatialIndex<'a> {
+        let mut cells: HashMap<_, Vec<_>> = HashMap::new();
+        let mut extent: Option<Rect> = None;
+
+        let bounds = objects
+            .objects
+            .iter()
+            .map(|object| bounding_box(object, footprint(object)))
+            .collect::<Vec<_>>();
+
+        for (idx, rect) in bounds.iter().enumerate() {
+            for cell in cells_overlapping(rect) {
+                cells.entry(cell).or_default().push(idx);
+            }
+
+            extent = Some(match extent {
+                Some(extent) => extent.union(rect),
+                None => *rect,
+            });
+        }
+
+        SpatialIndex {
+            objects: &objects.objects,
+            bounds,
+            cells,
+            extent,
+        }
+    }
+
+    /// All objects overlapping the given rectangle
+    pub fn in_rect(&self, rect: Rect) -> Vec<&'a LevelObject<'static>> {
+        // Restricting the query to the area actually containing objects bounds the amount of cells
+        // we have to look at, even for huge query rectangles
+        let rect = match self.extent.and_then(|extent| extent.intersection(&rect)) {
+            Some(rect) => rect,
+            None => return Vec::new(),
+        };
+
+        // The extent of a level with objects far apart from each other still spans a lot of empty
+        // cells. Scanning all objects is cheaper than visiting more cells than are occupied.
+        if cell_count(&rect) > self.cells.len() as u64 {
+            return self
+                .bounds
+                .iter()
+                .zip(self.objects)
+                .filter(|(bounds, _)| bounds.intersects(&rect))
+                .map(|(_, object)| object)
+                .collect();
+        }
+
+        let mut indices = cells_overlapping(&rect)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(|&idx| self.bounds[idx].intersects(&rect))
+            .collect::<Vec<_>>();
+
+        // objects spanning multiple cells are found multiple times
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices.into_iter().map(|idx| &self.objects[idx]).collect()
+    }
+
+    /// All objects overlapping the vertical strip between the given x positions
+    pub fn in_x_range(&self, min_x: f32, max_x: f32) -> Vec<&'a LevelObject<'static>> {
+        match self.extent {
+            Some(extent) => self.in_rect(Rect::new(min_x, extent.min_y, max_x, extent.max_y)),
+            None => Vec::new(),
+        }
+    }
+
+    /// All objects whose position is at most `radius` units away from the given point, ordered by
+    /// their distance to it (closest first)
+    pub fn near(&self, x: f32, y: f32, radius: f32) -> Vec<&'a LevelObject<'static>> {
+        let distance = |object: &LevelObject| (object.x - x).hypot(object.y - y);
+
+        let mut objects = self
+            .in_rect(Rect::new(x - radius, y - radius, x + radius, y + radius))
+            .into_iter()
+            .filter(|object| distance(object) <= radius)
+            .collect::<Vec<_>>();
+
+        objects.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+        objects
+    }
+}
+
+/// Computes the axis-aligned bounding box of an object with the given (unrotated) size
+fn bounding_box(object: &LevelObject, (width, height): (f32, f32)) -> Rect {
+    let (sin, cos) = object.rotation.to_radians().sin_cos();
+    let half_width = (width * cos.abs() + height * sin.abs()) / 2.0;
+    let half_height = (width * sin.abs() + height * cos.abs()) / 2.0;
+
+    Rect {
+        min_x: object.x - half_width,
+        min_y: object.y - half_height,
+        max_x: object.x + half_width,
+        max_y: object.y + half_height,
+    }
+}
+
+fn cells_overlapping(rect: &Rect) -> impl Iterator<Item = (i32, i32)> {
+    let (min_x, max_x) = (cell_coordinate(rect.min_x), cell_coordinate(rect.max_x));
+    let (min_y, max_y) = (cell_coordinate(rect.min_y), cell_coordinate(rect.max_y));
+
+    (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+}
+
+/// The number of cells [`cells_overlapping`] yields for the given rectangle
+fn cell_count(rect: &Rect) -> u64 {
+    let width = cell_coordinate(rect.max_x) as i64 - cell_coordinate(rect.min_x) as i64 + 1;
+    let height = cell_coordinate(rect.max_y) as i64 - cell_coordinate(rect.min_y) as i64 + 1;
+
+    width as u64 * height as u64
+}
+
+fn cell_coordinate(value: f32) -> i32 {
+    (value / CELL_SIZE).floor() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::{
+            metadata::LevelMetadata,
+            object::LevelObject,
+            spatial::{Rect, SpatialIndex},
+            LevelCompression, Objects,
+        },
+        GJFormat, IntoOwned,
+    };
+
+    fn objects() -> Objects {
+        Objects {
+            meta: LevelMetadata::default(),
+            objects: [
+                "1,1,2,15,3,15",
+                "1,1,2,45,3,15",
+                "1,1,2,1005,3,315",
+                // a 4x1 block, rotated to stand upright
+                "1,1,2,615,3,15,6,90",
+                "1,1,2,-45,3,105,4,1",
+            ]
+            .iter()
+            .map(|raw| LevelObject::from_gj_str(raw).unwrap().into_owned())
+            .collect(),
+            compression: LevelCompression::default(),
+        }
+    }
+
+    fn xs(objects: Vec<&LevelObject>) -> Vec<f32> {
+        objects.into_iter().map(|object| object.x).collect()
+    }
+
+    #[test]
+    fn rect_queries() {
+        let objects = objects();
+        let index = SpatialIndex::new(&objects);
+
+        assert_eq!(xs(index.in_rect(Rect::new(0.0, 0.0, 60.0, 30.0))), vec![15.0, 45.0]);
+        assert_eq!(xs(index.in_rect(Rect::new(29.0, 0.0, 31.0, 1.0))), vec![15.0, 45.0]);
+        assert_eq!(xs(index.in_rect(Rect::new(-1000.0, -1000.0, 10000.0, 10000.0))).len(), 5);
+        assert_eq!(xs(index.in_rect(Rect::new(2000.0, 0.0, 3000.0, 30.0))), Vec::<f32>::new());
+        assert_eq!(xs(index.in_x_range(900.0, 1000.0)), vec![1005.0]);
+        assert_eq!(xs(index.in_x_range(-100.0, 0.0)), vec![15.0, -45.0]);
+    }
+
+    #[test]
+    fn rotated_footprint() {
+        let objects = objects();
+        let index = SpatialIndex::with_footprint(&objects, |object| if object.x == 615.0 { (120.0, 30.0) } else { (30.0, 30.0) });
+
+        assert_eq!(xs(index.in_rect(Rect::new(600.0, 60.0, 630.0, 70.0))), vec![615.0]);
+        assert_eq!(xs(index.in_rect(Rect::new(650.0, 0.0, 660.0, 30.0))), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn sparse_objects() {
+        let mut objects = objects();
+        objects
+            .objects
+            .push(LevelObject::from_gj_str("1,1,2,1e9,3,-1e9").unwrap().into_owned());
+        let index = SpatialIndex::new(&objects);
+
+        let everything = Rect::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::INFINITY, f32::INFINITY);
+
+        assert_eq!(index.in_rect(everything).len(), 6);
+        assert_eq!(xs(index.in_rect(Rect::new(0.0, -2e9, f32::INFINITY, -100.0))), vec![1e9]);
+        assert_eq!(xs(index.in_x_range(0.0, 50.0)), vec![15.0, 45.0]);
+    }
+
+    #[test]
+    fn near_point() {
+        let objects = objects();
+        let index = SpatialIndex::new(&objects);
+
+        assert_eq!(xs(index.near(40.0, 15.0, 30.0)), vec![45.0, 15.0]);
+        assert_eq!(xs(index.near(1000.0, 300.0, 10.0)), Vec::<f32>::new());
+    }
+}
@@ -0,0 +1,239 @@
+//! Module containing types for calculating the duration of a level and for mapping between x
+//! positions and points in time
+
+use crate::model::level::object::speed::Speed;
+use serde::{Deserialize, Serialize};
+
+/// A section of a level during which the player moves at a constant speed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimingSegment {
+    /// The speed the player moves at during this segment
+    pub speed: Speed,
+
+    /// The x position at which this segment starts
+    pub start_x: f32,
+
+    /// The x position at which this segment ends
+    pub end_x: f32,
+
+    /// The amount of seconds the player needs to move through this segment
+    pub seconds: f32,
+}
+
+/// The timing of a (non-platformer) level, split into segments of constant speed
+///
+/// The segments are consecutive, meaning each segment starts where the previous one ended. There
+/// is always at least one segment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelTiming {
+    segments: Vec<TimingSegment>,
+}
+
+impl LevelTiming {
+    /// Calculates the timing of a level played from `start_x` to `end_x`, with the player
+    /// initially moving at `start_speed`.
+    ///
+    /// The given speed changes are `(x position, new speed)` pairs and do not need to be sorted.
+    /// Speed changes outside of the played range are ignored. A speed change placed exactly at
+    /// `start_x` overrides `start_speed`.
+    pub fn new(start_x: f32, end_x: f32, start_speed: Speed, speed_changes: impl IntoIterator<Item = (f32, Speed)>) -> LevelTiming {
+        let end_x = end_x.max(start_x);
+
+        let mut speed_changes = speed_changes
+            .into_iter()
+            .filter(|&(x, _)| x >= start_x && x < end_x)
+            .collect::<Vec<_>>();
+
+        // stable sort, so that of multiple speed changes at the same position the one appearing last
+        // in the input takes effect
+        speed_changes.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+
+        let at_start = speed_changes.iter().take_while(|&&(x, _)| x == start_x).count();
+        let mut speed = match at_start {
+            0 => start_speed,
+            _ => speed_changes[at_start - 1].1,
+        };
+
+        let mut segments = Vec::with_capacity(speed_changes.len() - at_start + 1);
+        let mut segment_start = start_x;
+
+        for (x, new_speed) in speed_changes
+            .into_iter()
+            .skip(at_start)
+            .chain(std::iter::once((end_x, start_speed)))
+        {
+            segments.push(TimingSegment {
+                speed,
+                start_x: segment_start,
+                end_x: x,
+                seconds: (x - segment_start) / pixels_per_second(speed),
+            });
+
+            segment_start = x;
+            speed = new_speed;
+        }
+
+        LevelTiming { segments }
+    }
+
+    /// The segments of constant speed this level consists of, ordered by their x position
+    pub fn segments(&self) -> &[TimingSegment] {
+        &self.segments
+    }
+
+    /// The x position the player starts at
+    pub fn start_x(&self) -> f32 {
+        self.segments[0].start_x
+    }
+
+    /// The x position at which the level ends
+    pub fn end_x(&self) -> f32 {
+        self.last_segment().end_x
+    }
+
+    /// The time (in seconds) it takes the player to get from the start to the end of the level
+    pub fn duration(&self) -> f32 {
+        self.segments.iter().map(|segment| segment.seconds).sum()
+    }
+
+    /// The speed the player moves at when at the given x position
+    pub fn speed_at(&self, x: f32) -> Speed {
+        self.segments
+            .iter()
+            .find(|segment| x < segment.end_x)
+            .unwrap_or_else(|| self.last_segment())
+            .speed
+    }
+
+    /// Calculates the amount of seconds the player needs to reach the given x position.
+    ///
+    /// Positions before the start of the level result in negative values, positions past its end
+    /// are extrapolated using the speed of the last segment.
+    pub fn seconds_at(&self, x: f32) -> f32 {
+        let mut elapsed = 0.0;
+
+        for segment in &self.segments[..self.segments.len() - 1] {
+            if x <= segment.end_x {
+                return elapsed + (x - segment.start_x) / pixels_per_second(segment.speed);
+            }
+
+            elapsed += segment.seconds;
+        }
+
+        let last = self.last_segment();
+
+        elapsed + (x - last.start_x) / pixels_per_second(last.speed)
+    }
+
+    /// Calculates the x position the player is at after the given amount of seconds. This is the
+    /// inverse of [`LevelTiming::seconds_at`].
+    pub fn x_at(&self, seconds: f32) -> f32 {
+        let mut remaining = seconds;
+
+        for segment in &self.segments[..self.segments.len() - 1] {
+            if remaining <= segment.seconds {
+                return segment.start_x + remaining * pixels_per_second(segment.speed);
+            }
+
+            remaining -= segment.seconds;
+        }
+
+        let last = self.last_segment();
+
+        last.start_x + remaining * pixels_per_second(last.speed)
+    }
+
+    fn last_segment(&self) -> &TimingSegment {
+        // There always is at least one segment, see the constructor
+        &self.segments[self.segments.len() - 1]
+    }
+}
+
+/// The amount of pixels per second the player moves at the given speed. Unknown speeds are treated
+/// as normal speed.
+fn pixels_per_second(speed: Speed) -> f32 {
+    match speed {
+        Speed::Unknown(_) => Speed::Normal.into(),
+        known => known.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::level::{
+        object::speed::Speed,
+        timing::{LevelTiming, TimingSegment},
+    };
+
+    #[test]
+    fn segments() {
+        let timing = LevelTiming::new(
+            0.0,
+            4000.0,
+            Speed::Normal,
+            [(3000.0, Speed::Slow), (1000.0, Speed::Fast), (5000.0, Speed::VeryFast)],
+        );
+
+        assert_eq!(
+            timing.segments(),
+            &[
+                TimingSegment {
+                    speed: Speed::Normal,
+                    start_x: 0.0,
+                    end_x: 1000.0,
+                    seconds: 1000.0 / 311.58
+                },
+                TimingSegment {
+                    speed: Speed::Fast,
+                    start_x: 1000.0,
+                    end_x: 3000.0,
+                    seconds: 2000.0 / 468.0
+                },
+                TimingSegment {
+                    speed: Speed::Slow,
+                    start_x: 3000.0,
+                    end_x: 4000.0,
+                    seconds: 1000.0 / 251.16
+                },
+            ]
+        );
+        assert_eq!(timing.speed_at(2000.0), Speed::Fast);
+        assert_eq!(timing.speed_at(5000.0), Speed::Slow);
+    }
+
+    #[test]
+    fn x_pos_seconds_conversion() {
+        let timing = LevelTiming::new(0.0, 4000.0, Speed::Normal, [(1000.0, Speed::Fast), (3000.0, Speed::Slow)]);
+
+        assert_eq!(timing.seconds_at(311.58), 1.0);
+        assert_eq!(timing.seconds_at(2000.0), 1000.0 / 311.58 + 1000.0 / 468.0);
+        assert!((timing.seconds_at(4000.0) - timing.duration()).abs() < 0.0001);
+
+        for x in [-500.0, 0.0, 500.0, 1000.0, 2500.0, 3000.0, 4000.0, 5000.0] {
+            assert!((timing.x_at(timing.seconds_at(x)) - x).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn start_after_speed_change() {
+        let timing = LevelTiming::new(2000.0, 3000.0, Speed::Medium, [(1000.0, Speed::Fast)]);
+
+        assert_eq!(timing.segments().len(), 1);
+        assert_eq!(timing.duration(), 1000.0 / 387.42);
+        assert_eq!(timing.seconds_at(2000.0), 0.0);
+    }
+
+    #[test]
+    fn speed_change_at_start() {
+        let timing = LevelTiming::new(
+            1000.0,
+            3000.0,
+            Speed::Normal,
+            [(1000.0, Speed::Slow), (1000.0, Speed::Fast), (2000.0, Speed::Medium)],
+        );
+
+        assert_eq!(timing.segments().len(), 2);
+        assert_eq!(timing.speed_at(1000.0), Speed::Fast);
+        assert_eq!(timing.duration(), 1000.0 / 468.0 + 1000.0 / 387.42);
+    }
+}
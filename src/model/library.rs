@@ -0,0 +1,363 @@
+//! Module containing models for the music and sound effect libraries introduced in Geometry Dash
+//! 2.2
+//!
+//! ## GD Internals:
+//! The game downloads the libraries as `musiclibrary.dat` and `sfxlibrary.dat`. Both files are
+//! compressed and base64 encoded the same way as level data (see
+//! [`Objects`](crate::model::level::Objects)). After decompression, they consist of several
+//! sections separated by `|`, each of which is a `;`-separated list of list-like objects whose
+//! fields are separated by `,`.
+
+use std::borrow::Cow;
+
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+use variant_partial_eq::VariantPartialEq;
+
+use crate::{
+    model::level::{decompress_level_data, LevelProcessError},
+    response::ResponseError,
+    GJFormat,
+};
+
+/// Decompresses the contents of `musiclibrary.dat` or `sfxlibrary.dat`
+pub fn decompress_library(data: &str) -> Result<String, LevelProcessError> {
+    decompress_level_data(data.trim()).map(|(decompressed, _)| decompressed)
+}
+
+/// Parses all non-empty `;`-separated objects of the given section
+fn parse_section<'a, T: GJFormat<'a>>(section: &'a str) -> Result<Vec<T>, ResponseError<'a>> {
+    section
+        .split(';')
+        .filter(|object| !object.is_empty())
+        .map(|object| T::from_gj_str(object).map_err(ResponseError::De))
+        .collect()
+}
+
+/// The music library, containing all songs that can be used by levels besides the [`MainSong`]s
+/// and [`NewgroundsSong`]s
+///
+/// [`MainSong`]: crate::model::song::MainSong
+/// [`NewgroundsSong`]: crate::model::song::NewgroundsSong
+///
+/// ## GD Internals:
+/// The decompressed library has the format `version|artists|songs|tags`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, IntoOwned)]
+pub struct MusicLibrary<'a> {
+    /// The version of the library. The game redownloads the library if the servers report a newer
+    /// version.
+    pub version: u32,
+
+    #[serde(borrow)]
+    pub artists: Vec<LibraryArtist<'a>>,
+
+    #[serde(borrow)]
+    pub songs: Vec<LibrarySong<'a>>,
+
+    #[serde(borrow)]
+    pub tags: Vec<LibraryTag<'a>>,
+}
+
+impl<'a> MusicLibrary<'a> {
+    /// Parses an already decompressed music library (see [`decompress_library`])
+    pub fn from_decompressed(decompressed: &'a str) -> Result<MusicLibrary<'a>, ResponseError<'a>> {
+        let mut sections = decompressed.split('|');
+
+        let version = sections.next().and_then(|version| version.parse().ok());
+        let (version, artists, songs, tags) = match (version, sections.next(), sections.next(), sections.next()) {
+            (Some(version), Some(artists), Some(songs), Some(tags)) => (version, artists, songs, tags),
+            _ => return Err(ResponseError::UnexpectedFormat),
+        };
+
+        Ok(MusicLibrary {
+            version,
+            artists: parse_section(artists)?,
+            songs: parse_section(songs)?,
+            tags: parse_section(tags)?,
+        })
+    }
+
+    /// Looks up the song with the given ID, e.g. the [`custom_song`](crate::model::level::Level::custom_song)
+    /// of a level
+    pub fn song(&self, song_id: u64) -> Option<&LibrarySong<'a>> {
+        self.songs.iter().find(|song| song.song_id == song_id)
+    }
+
+    pub fn artist(&self, artist_id: u64) -> Option<&LibraryArtist<'a>> {
+        self.artists.iter().find(|artist| artist.artist_id == artist_id)
+    }
+
+    pub fn tag(&self, tag_id: u64) -> Option<&LibraryTag<'a>> {
+        self.tags.iter().find(|tag| tag.tag_id == tag_id)
+    }
+
+    /// The artist of the given song, if they are part of this library
+    pub fn artist_of(&self, song: &LibrarySong) -> Option<&LibraryArtist<'a>> {
+        self.artist(song.artist_id)
+    }
+}
+
+/// An artist whose songs are part of the [`MusicLibrary`]
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LibraryArtist<'a> {
+    #[dash(index = 1)]
+    pub artist_id: u64,
+
+    #[dash(index = 2)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    /// The link to the artist's website, if they have one
+    #[dash(index = 3)]
+    #[dash(default)]
+    pub website: Option<Cow<'a, str>>,
+
+    /// The ID of the artist's YouTube channel, if they have one
+    #[dash(index = 4)]
+    #[dash(default)]
+    pub youtube_channel_id: Option<Cow<'a, str>>,
+}
+
+impl<'de> GJFormat<'de> for LibraryArtist<'de> {
+    const DELIMITER: &'static str = ",";
+    const MAP_LIKE: bool = false;
+}
+
+/// A song in the [`MusicLibrary`]
+///
+/// Library songs use IDs that do not overlap with the IDs of [`NewgroundsSong`](crate::model::song::NewgroundsSong)s,
+/// and are referenced by levels in the same way.
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LibrarySong<'a> {
+    #[dash(index = 1)]
+    pub song_id: u64,
+
+    #[dash(index = 2)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    /// The ID of the song's [`LibraryArtist`]
+    #[dash(index = 3)]
+    pub artist_id: u64,
+
+    /// The size of the song file, in bytes
+    #[dash(index = 4)]
+    pub filesize: u64,
+
+    /// The length of the song, in seconds
+    #[dash(index = 5)]
+    pub duration: u32,
+
+    /// The IDs of the [`LibraryTag`]s of this song, see [`LibrarySong::tag_ids`]
+    ///
+    /// ## GD Internals:
+    /// The tag IDs are surrounded and separated by dots, e.g. `.1.23.4.`
+    #[dash(index = 6)]
+    #[dash(default)]
+    pub tags: Cow<'a, str>,
+
+    #[dash(index = 7)]
+    #[dash(default)]
+    pub index_7: Option<Cow<'a, str>>,
+
+    /// The IDs of additional artists that worked on this song, separated by dots
+    #[dash(index = 8)]
+    #[dash(default)]
+    pub extra_artists: Option<Cow<'a, str>>,
+
+    /// A link to the song outside of the game, if available
+    #[dash(index = 9)]
+    #[dash(default)]
+    pub external_link: Option<Cow<'a, str>>,
+
+    /// Whether the game marks this song as new
+    #[dash(index = 10)]
+    #[dash(default)]
+    pub is_new: bool,
+
+    #[dash(index = 11)]
+    #[dash(default)]
+    pub priority: i32,
+}
+
+impl<'de> GJFormat<'de> for LibrarySong<'de> {
+    const DELIMITER: &'static str = ",";
+    const MAP_LIKE: bool = false;
+}
+
+impl<'a> LibrarySong<'a> {
+    /// The IDs of this song's [`LibraryTag`]s. Malformed IDs are skipped.
+    pub fn tag_ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.tags.split('.').filter_map(|id| id.parse().ok())
+    }
+}
+
+/// A tag used to categorize the songs in the [`MusicLibrary`]
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LibraryTag<'a> {
+    #[dash(index = 1)]
+    pub tag_id: u64,
+
+    #[dash(index = 2)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+}
+
+impl<'de> GJFormat<'de> for LibraryTag<'de> {
+    const DELIMITER: &'static str = ",";
+    const MAP_LIKE: bool = false;
+}
+
+/// The sound effect library, containing all sound effects that can be used by SFX triggers
+///
+/// ## GD Internals:
+/// The decompressed library has the format `entries|credits`. The sound effects are organized in a
+/// tree of folders, with each [`SfxEntry`] referencing its parent folder.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, IntoOwned)]
+pub struct SfxLibrary<'a> {
+    #[serde(borrow)]
+    pub entries: Vec<SfxEntry<'a>>,
+
+    #[serde(borrow)]
+    pub credits: Vec<SfxCredit<'a>>,
+}
+
+impl<'a> SfxLibrary<'a> {
+    /// Parses an already decompressed sound effect library (see [`decompress_library`])
+    pub fn from_decompressed(decompressed: &'a str) -> Result<SfxLibrary<'a>, ResponseError<'a>> {
+        let mut sections = decompressed.split('|');
+
+        let entries = match sections.next() {
+            Some(entries) => entries,
+            None => return Err(ResponseError::UnexpectedFormat),
+        };
+
+        Ok(SfxLibrary {
+            entries: parse_section(entries)?,
+            credits: match sections.next() {
+                Some(credits) => parse_section(credits)?,
+                None => Vec::new(),
+            },
+        })
+    }
+
+    /// Looks up the sound effect or folder with the given ID
+    pub fn entry(&self, id: u64) -> Option<&SfxEntry<'a>> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// All sound effects and folders directly contained in the folder with the given ID
+    pub fn children(&self, folder_id: u64) -> impl Iterator<Item = &SfxEntry<'a>> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.parent_id == folder_id && entry.id != folder_id)
+    }
+}
+
+/// A sound effect or folder in the [`SfxLibrary`]
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct SfxEntry<'a> {
+    #[dash(index = 1)]
+    pub id: u64,
+
+    #[dash(index = 2)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    #[dash(index = 3)]
+    pub is_folder: bool,
+
+    /// The ID of the folder containing this entry
+    #[dash(index = 4)]
+    pub parent_id: u64,
+
+    /// The size of the sound effect file, in bytes. Always `0` for folders.
+    #[dash(index = 5)]
+    #[dash(default)]
+    pub filesize: u64,
+
+    /// The length of the sound effect. Always `0` for folders.
+    #[dash(index = 6)]
+    #[dash(default)]
+    pub duration: u32,
+}
+
+impl<'de> GJFormat<'de> for SfxEntry<'de> {
+    const DELIMITER: &'static str = ",";
+    const MAP_LIKE: bool = false;
+}
+
+/// An author credited for some of the sound effects in the [`SfxLibrary`]
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct SfxCredit<'a> {
+    #[dash(index = 1)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    #[dash(index = 2)]
+    #[dash(default)]
+    pub website: Option<Cow<'a, str>>,
+}
+
+impl<'de> GJFormat<'de> for SfxCredit<'de> {
+    const DELIMITER: &'static str = ",";
+    const MAP_LIKE: bool = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        level::LevelCompression,
+        library::{decompress_library, MusicLibrary, SfxLibrary},
+    };
+
+    const MUSIC_LIBRARY: &str = "101|10000002,Boom Kitty,https://boomkitty.com,UC2mcS6-tQ4ov4E_ofQQ5Vjw;10000012,Waterflame,,;|10000042,Ego Death,10000002,4235789,151,.5.12.,0,,,1,0;10001234,Blast Processing,10000012,2512331,97,.12.,0;|5,Electronic;12,Dubstep;";
+
+    const SFX_LIBRARY: &str = "1,sfx,1,0,0,0;2,Explosions,1,1;3,Boom,0,2,12034,75;4,Big Boom,0,2,30412,140;|Someone,https://example.com;";
+
+    #[test]
+    fn parse_music_library() {
+        let library = MusicLibrary::from_decompressed(MUSIC_LIBRARY).unwrap();
+
+        assert_eq!(library.version, 101);
+        assert_eq!(library.artists.len(), 2);
+        assert_eq!(library.songs.len(), 2);
+        assert_eq!(library.tags.len(), 2);
+
+        let song = library.song(10000042).unwrap();
+
+        assert_eq!(song.name, "Ego Death");
+        assert_eq!(song.duration, 151);
+        assert!(song.is_new);
+        assert_eq!(song.tag_ids().collect::<Vec<_>>(), vec![5, 12]);
+        assert_eq!(library.artist_of(song).unwrap().name, "Boom Kitty");
+        assert_eq!(library.tag(5).unwrap().name, "Electronic");
+
+        // trailing fields may be missing
+        let song = library.song(10001234).unwrap();
+
+        assert_eq!(library.artist_of(song).unwrap().website, None);
+        assert!(!song.is_new);
+        assert_eq!(song.priority, 0);
+        assert!(library.song(1).is_none());
+    }
+
+    #[test]
+    fn parse_sfx_library() {
+        let library = SfxLibrary::from_decompressed(SFX_LIBRARY).unwrap();
+
+        assert_eq!(library.entries.len(), 4);
+        assert!(library.entry(2).unwrap().is_folder);
+        assert_eq!(library.children(1).map(|entry| entry.id).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(library.children(2).map(|entry| entry.id).collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(library.credits[0].name, "Someone");
+    }
+
+    #[test]
+    fn decompress() {
+        let compressed = LevelCompression::default().compress(MUSIC_LIBRARY.as_bytes()).unwrap();
+
+        assert_eq!(decompress_library(&compressed).unwrap(), MUSIC_LIBRARY);
+        assert!(MusicLibrary::from_decompressed("garbage").is_err());
+    }
+}
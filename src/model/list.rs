@@ -0,0 +1,193 @@
+//! Module containing the model for level lists, the player-created level collections introduced in
+//! Geometry Dash 2.2
+
+use std::borrow::Cow;
+
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+use variant_partial_eq::VariantPartialEq;
+
+use crate::{
+    model::{
+        creator::Creator,
+        level::{DemonRating, Featured, LevelRating},
+    },
+    serde::{Base64Decoder, IdList, Thunk},
+    Dash, GJFormat,
+};
+
+/// A [`LevelList`] whose creator has been resolved from the creator section of a
+/// `getGJLevelLists` response
+pub type ListedLevelList<'a> = LevelList<'a, Option<Creator<'a>>>;
+
+/// Struct representing a level list as returned by the boomlings API.
+///
+/// These can be retrieved using [`LevelListsRequest`](crate::request::list::LevelListsRequest)s.
+///
+/// ## GD Internals:
+/// The Geometry Dash servers provide lists of level lists via the `getGJLevelLists` endpoint
+///
+/// ### Unmapped values:
+/// + Index `50`: The name of the list's creator. This is the same value as the name provided in the
+///   creator section of the response, which is where dash-rs takes it from.
+#[derive(Debug, VariantPartialEq, Serialize, Deserialize, Dash, IntoOwned)]
+#[dash(instance)]
+pub struct LevelList<'a, User = u64> {
+    /// The list's unique ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`.
+    #[dash(index = 1)]
+    pub list_id: u64,
+
+    /// The list's name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`.
+    #[dash(index = 2)]
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    /// The list's description. Is [`None`] if the creator didn't put any description.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3` and encoded using urlsafe base 64.
+    #[dash(index = 3)]
+    #[variant_compare = "crate::util::option_variant_eq"]
+    pub description: Option<Thunk<'a, Base64Decoder>>,
+
+    /// The [`LevelList`]'s version. The version get incremented every time the list is updated,
+    /// and the initial version is always version 1.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `5`.
+    #[dash(index = 5)]
+    pub version: u32,
+
+    /// The difficulty icon displayed next to this [`LevelList`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`. Unlike for levels, all difficulties (including demon
+    /// difficulties) are encoded in this single value.
+    #[dash(composite(from = "difficulty_from_raw", into = "difficulty_into_raw"))]
+    #[dash(raw(index = 7, ty = i32))]
+    pub difficulty: LevelRating,
+
+    /// The amount of downloads
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    #[dash(index = 10)]
+    pub downloads: u32,
+
+    /// The amount of likes this [`LevelList`] has received
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    #[dash(index = 14)]
+    pub likes: i32,
+
+    /// This [`LevelList`]'s featured state
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `19`
+    #[dash(index = 19)]
+    pub featured: Featured,
+
+    /// The time this [`LevelList`] was uploaded, as a unix timestamp
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `28`. Unlike for levels, the servers provide an exact
+    /// timestamp here.
+    #[dash(index = 28)]
+    pub uploaded: u64,
+
+    /// The time this [`LevelList`] was last updated, as a unix timestamp, or [`None`] if it was
+    /// never updated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `29`, and a value of `0` means the list was never updated
+    #[dash(index = 29)]
+    #[dash(with = "crate::util::default_to_none")]
+    pub updated: Option<u64>,
+
+    /// The account ID of the list's creator
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `49`. Unlike levels, which reference their creator by user
+    /// ID, lists reference their creator by account ID.
+    #[dash(index = 49)]
+    pub creator: User,
+
+    /// The IDs of the levels contained in this [`LevelList`], in the order they are displayed
+    /// in-game
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51` as a comma separated list
+    #[dash(index = 51)]
+    pub levels: Thunk<'a, IdList>,
+
+    /// The amount of diamonds awarded for completing [`LevelList::reward_threshold`] levels of this
+    /// [`LevelList`], or [`None`] if the list awards no diamonds.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `55`, and a value of `0` means no reward
+    #[dash(index = 55)]
+    #[dash(with = "crate::util::default_to_none")]
+    pub reward: Option<u32>,
+
+    /// The amount of levels that need to be completed to claim [`LevelList::reward`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `56`
+    #[dash(index = 56)]
+    pub reward_threshold: u32,
+}
+
+impl<'de, User> GJFormat<'de> for LevelList<'de, User>
+where
+    LevelList<'de, User>: Dash<'de>,
+{
+    const DELIMITER: &'static str = ":";
+    const MAP_LIKE: bool = true;
+}
+
+/// Constructs a level list's difficulty from the value at index `7`
+///
+/// The value `-1` means N/A, `0` to `5` correspond to auto to insane and `6` to `10` are the demon
+/// difficulties from easy to extreme.
+fn difficulty_from_raw(value: i32) -> LevelRating {
+    match value {
+        -1 => LevelRating::NotAvailable,
+        0 => LevelRating::Auto,
+        1 => LevelRating::Easy,
+        2 => LevelRating::Normal,
+        3 => LevelRating::Hard,
+        4 => LevelRating::Harder,
+        5 => LevelRating::Insane,
+        6 => LevelRating::Demon(DemonRating::Easy),
+        7 => LevelRating::Demon(DemonRating::Medium),
+        8 => LevelRating::Demon(DemonRating::Hard),
+        9 => LevelRating::Demon(DemonRating::Insane),
+        10 => LevelRating::Demon(DemonRating::Extreme),
+        _ => LevelRating::Unknown(value),
+    }
+}
+
+fn difficulty_into_raw(difficulty: &LevelRating) -> i32 {
+    match *difficulty {
+        LevelRating::NotAvailable => -1,
+        LevelRating::Auto => 0,
+        LevelRating::Easy => 1,
+        LevelRating::Normal => 2,
+        LevelRating::Hard => 3,
+        LevelRating::Harder => 4,
+        LevelRating::Insane => 5,
+        LevelRating::Demon(DemonRating::Easy) => 6,
+        LevelRating::Demon(DemonRating::Medium) => 7,
+        LevelRating::Demon(DemonRating::Hard) => 8,
+        LevelRating::Demon(DemonRating::Insane) => 9,
+        LevelRating::Demon(DemonRating::Extreme) => 10,
+        LevelRating::Demon(DemonRating::Unknown(value)) | LevelRating::Unknown(value) => value,
+    }
+}
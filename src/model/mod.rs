@@ -20,7 +20,11 @@ use std::fmt::{Display, Formatter};
 pub mod comment;
 pub mod creator;
 pub mod level;
+pub mod library;
+pub mod list;
+pub mod pack;
 pub mod song;
+pub mod time;
 pub mod user;
 
 /// Enum modelling the version of a Geometry Dash client
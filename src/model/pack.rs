@@ -0,0 +1,152 @@
+//! Module containing the models for map packs and gauntlets, the two kinds of level collections
+//! curated by RobTop
+
+use std::borrow::Cow;
+
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+use variant_partial_eq::VariantPartialEq;
+
+use crate::{
+    model::{
+        level::{DemonRating, LevelRating},
+        user::Color,
+    },
+    serde::{IdList, Thunk},
+    GJFormat,
+};
+
+/// Struct representing a map pack
+///
+/// Map packs can be retrieved using a
+/// [`MapPacksRequest`](crate::request::pack::MapPacksRequest). The levels they contain can be
+/// retrieved using a [`LevelsRequest`](crate::request::level::LevelsRequest) of type
+/// [`LevelRequestType::MapPack`](crate::request::level::LevelRequestType::MapPack).
+///
+/// ## GD Internals:
+/// The Geometry Dash servers provide lists of map packs via the `getGJMapPacks21` endpoint
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct MapPack<'a> {
+    /// The [`MapPack`]'s unique ID
+    #[dash(index = 1)]
+    pub pack_id: u64,
+
+    /// The [`MapPack`]'s name
+    #[serde(borrow)]
+    #[dash(index = 2)]
+    pub name: Cow<'a, str>,
+
+    /// The IDs of the levels contained in this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3` as a comma separated list
+    #[dash(index = 3)]
+    pub levels: Thunk<'a, IdList>,
+
+    /// The amount of stars awarded for completing all levels in this [`MapPack`]
+    #[dash(index = 4)]
+    pub stars: u8,
+
+    /// The amount of secret coins awarded for completing all levels in this [`MapPack`]
+    #[dash(index = 5)]
+    pub coins: u8,
+
+    /// The difficulty icon displayed next to this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`. Unlike for levels, all difficulties (including demon
+    /// difficulties) are encoded in this single value.
+    #[dash(composite(from = "difficulty_from_raw", into = "difficulty_into_raw"))]
+    #[dash(raw(index = 6, ty = i32))]
+    pub difficulty: LevelRating,
+
+    /// The color of this [`MapPack`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7` as a comma separated RGB triple
+    #[dash(index = 7)]
+    pub text_color: Thunk<'a, Color>,
+
+    /// The color of the progress bar displayed for this [`MapPack`]. Very old map packs do not
+    /// specify this color.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8` as a comma separated RGB triple
+    #[dash(index = 8)]
+    #[variant_compare = "crate::util::option_variant_eq"]
+    pub bar_color: Option<Thunk<'a, Color>>,
+}
+
+impl<'de> GJFormat<'de> for MapPack<'de> {
+    const DELIMITER: &'static str = ":";
+    const MAP_LIKE: bool = true;
+}
+
+/// Constructs a map pack's difficulty from the value at index `6`
+///
+/// The values `0` to `5` correspond to auto to insane, `6` is a hard demon and `7` to `10` are the
+/// other demon difficulties from easy to extreme.
+fn difficulty_from_raw(value: i32) -> LevelRating {
+    match value {
+        0 => LevelRating::Auto,
+        1 => LevelRating::Easy,
+        2 => LevelRating::Normal,
+        3 => LevelRating::Hard,
+        4 => LevelRating::Harder,
+        5 => LevelRating::Insane,
+        6 => LevelRating::Demon(DemonRating::Hard),
+        7 => LevelRating::Demon(DemonRating::Easy),
+        8 => LevelRating::Demon(DemonRating::Medium),
+        9 => LevelRating::Demon(DemonRating::Insane),
+        10 => LevelRating::Demon(DemonRating::Extreme),
+        _ => LevelRating::Unknown(value),
+    }
+}
+
+fn difficulty_into_raw(difficulty: &LevelRating) -> i32 {
+    match *difficulty {
+        LevelRating::Auto => 0,
+        LevelRating::Easy => 1,
+        LevelRating::Normal => 2,
+        LevelRating::Hard => 3,
+        LevelRating::Harder => 4,
+        LevelRating::Insane => 5,
+        LevelRating::Demon(DemonRating::Hard) => 6,
+        LevelRating::Demon(DemonRating::Easy) => 7,
+        LevelRating::Demon(DemonRating::Medium) => 8,
+        LevelRating::Demon(DemonRating::Insane) => 9,
+        LevelRating::Demon(DemonRating::Extreme) => 10,
+        LevelRating::Demon(DemonRating::Unknown(value)) | LevelRating::Unknown(value) => value,
+        // Map packs always have a difficulty, so this value does not occur in responses
+        LevelRating::NotAvailable => -1,
+    }
+}
+
+/// Struct representing a gauntlet
+///
+/// Gauntlets can be retrieved using a
+/// [`GauntletsRequest`](crate::request::pack::GauntletsRequest). Unlike map packs, gauntlets do
+/// not have a name, instead their ID determines their name and appearance in-game (e.g. `1` is
+/// the fire gauntlet).
+///
+/// ## GD Internals:
+/// The Geometry Dash servers provide the list of gauntlets via the `getGJGauntlets21` endpoint
+#[derive(Debug, VariantPartialEq, Clone, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct Gauntlet<'a> {
+    /// The [`Gauntlet`]'s unique ID
+    #[dash(index = 1)]
+    pub gauntlet_id: u64,
+
+    /// The IDs of the levels contained in this [`Gauntlet`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3` as a comma separated list
+    #[serde(borrow)]
+    #[dash(index = 3)]
+    pub levels: Thunk<'a, IdList>,
+}
+
+impl<'de> GJFormat<'de> for Gauntlet<'de> {
+    const DELIMITER: &'static str = ":";
+    const MAP_LIKE: bool = true;
+}
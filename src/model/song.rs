@@ -1,5 +1,5 @@
-use crate::serde::{GJFormat, PercentDecoder, ProcessError, Thunk};
-use dash_rs_derive::Dash;
+use crate::serde::{GJFormat, PercentDecoder, Thunk};
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -15,7 +15,7 @@ use variant_partial_eq::VariantPartialEq;
 ///
 /// ### Unused indices:
 /// The following indices aren't used by the Geometry Dash servers: `9`
-#[derive(Debug, VariantPartialEq, Serialize, Deserialize, Clone, Dash)]
+#[derive(Debug, VariantPartialEq, Serialize, Deserialize, Clone, Dash, IntoOwned)]
 pub struct NewgroundsSong<'a> {
     /// The newgrounds id of this [`NewgroundsSong`]
     #[dash(index = 1)]
@@ -56,22 +56,6 @@ impl<'de> GJFormat<'de> for NewgroundsSong<'de> {
     const MAP_LIKE: bool = true;
 }
 
-impl<'a> NewgroundsSong<'a> {
-    pub fn into_owned(self) -> Result<NewgroundsSong<'static>, ProcessError> {
-        Ok(NewgroundsSong {
-            song_id: self.song_id,
-            name: Cow::Owned(self.name.into_owned()),
-            index_3: self.index_3,
-            artist: Cow::Owned(self.artist.into_owned()),
-            filesize: self.filesize,
-            index_6: self.index_6.map(|cow| Cow::Owned(cow.into_owned())),
-            index_7: self.index_7.map(|cow| Cow::Owned(cow.into_owned())),
-            index_8: Cow::Owned(self.index_8.into_owned()),
-            link: Thunk::Processed(Cow::Owned(self.link.into_processed()?.into_owned())),
-        })
-    }
-}
-
 /// Struct representing Geometry Dash's main songs.
 ///
 /// This data is not provided by the API and needs to be manually kept up to
@@ -157,3 +141,5 @@ impl From<MainSong> for u8 {
         song.main_song_id
     }
 }
+
+crate::into_conversion!(MainSong, u8);
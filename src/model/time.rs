@@ -0,0 +1,211 @@
+//! Module containing types for RobTop's imprecise way of describing how long ago something happened
+
+use crate::serde::{ProcessError, ThunkProcessor};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter},
+    time::{Duration, SystemTime},
+};
+
+/// The units of time the servers use when describing how long ago something happened
+///
+/// Variants are ordered from shortest to longest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// The approximate amount of seconds in one of this unit. Months are assumed to have 30 days,
+    /// years to have 365 days.
+    pub fn seconds(self) -> u64 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => 60,
+            TimeUnit::Hour => 60 * 60,
+            TimeUnit::Day => 24 * 60 * 60,
+            TimeUnit::Week => 7 * 24 * 60 * 60,
+            TimeUnit::Month => 30 * 24 * 60 * 60,
+            TimeUnit::Year => 365 * 24 * 60 * 60,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<TimeUnit> {
+        let singular = name.strip_suffix('s').unwrap_or(name);
+
+        [
+            TimeUnit::Second,
+            TimeUnit::Minute,
+            TimeUnit::Hour,
+            TimeUnit::Day,
+            TimeUnit::Week,
+            TimeUnit::Month,
+            TimeUnit::Year,
+        ]
+        .iter()
+        .copied()
+        .find(|unit| unit.name() == singular)
+    }
+}
+
+/// How long ago something (e.g. the upload of a level or the posting of a comment) happened
+///
+/// The values are as imprecise as RobTop's servers make them, e.g. "3 months" or "1 year". They are
+/// ordered by their unit first and their amount second, which matches the order of their
+/// approximate durations for all values the servers produce (as they always use the largest unit
+/// possible).
+///
+/// ## GD Internals:
+/// The servers provide these values as strings of the form `"<amount> <unit>"`, with the unit being
+/// pluralized if the amount is not `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct RelativeTime {
+    /// The unit of time [`RelativeTime::amount`] is given in
+    pub unit: TimeUnit,
+
+    /// How many of [`RelativeTime::unit`] have passed
+    pub amount: u32,
+}
+
+impl RelativeTime {
+    /// The approximate duration described by this [`RelativeTime`]. See [`TimeUnit::seconds`] for
+    /// how long each unit is assumed to be.
+    pub fn approximate_duration(&self) -> Duration {
+        Duration::from_secs(self.unit.seconds() * self.amount as u64)
+    }
+
+    /// The approximate point in time described by this [`RelativeTime`], if it is measured
+    /// relative to the given reference time (usually the time the server response was received).
+    ///
+    /// Returns [`None`] if the resulting point in time cannot be represented.
+    pub fn approximate_timestamp(&self, reference: SystemTime) -> Option<SystemTime> {
+        reference.checked_sub(self.approximate_duration())
+    }
+}
+
+impl Display for RelativeTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.amount == 1 {
+            write!(f, "1 {}", self.unit.name())
+        } else {
+            write!(f, "{} {}s", self.amount, self.unit.name())
+        }
+    }
+}
+
+crate::into_owned_identity!(RelativeTime);
+
+impl ThunkProcessor for RelativeTime {
+    type Error = ProcessError;
+    type Output<'a> = RelativeTime;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        let (amount, unit) = unprocessed
+            .split_once(' ')
+            .ok_or_else(|| ProcessError::UnknownTimeUnit(String::new()))?;
+
+        Ok(RelativeTime {
+            unit: TimeUnit::from_name(unit).ok_or_else(|| ProcessError::UnknownTimeUnit(unit.to_string()))?,
+            amount: amount.parse()?,
+        })
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        Ok(Cow::Owned(processed.to_string()))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::time::{RelativeTime, TimeUnit},
+        ThunkProcessor,
+    };
+    use std::{
+        borrow::Cow,
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn parse_relative_time() {
+        let time = RelativeTime::from_unprocessed(Cow::Borrowed("3 months")).unwrap();
+
+        assert_eq!(
+            time,
+            RelativeTime {
+                unit: TimeUnit::Month,
+                amount: 3
+            }
+        );
+        assert_eq!(RelativeTime::as_unprocessed(&time).unwrap(), "3 months");
+
+        let time = RelativeTime::from_unprocessed(Cow::Borrowed("1 year")).unwrap();
+
+        assert_eq!(
+            time,
+            RelativeTime {
+                unit: TimeUnit::Year,
+                amount: 1
+            }
+        );
+        assert_eq!(RelativeTime::as_unprocessed(&time).unwrap(), "1 year");
+    }
+
+    #[test]
+    fn parse_malformed_relative_time() {
+        assert!(RelativeTime::from_unprocessed(Cow::Borrowed("")).is_err());
+        assert!(RelativeTime::from_unprocessed(Cow::Borrowed("3 fortnights")).is_err());
+        assert!(RelativeTime::from_unprocessed(Cow::Borrowed("a year")).is_err());
+    }
+
+    #[test]
+    fn relative_time_ordering() {
+        let mut times = ["1 year", "5 days", "11 months", "2 weeks", "30 seconds"]
+            .map(|time| RelativeTime::from_unprocessed(Cow::Borrowed(time)).unwrap());
+
+        times.sort();
+
+        assert_eq!(
+            times.map(|time| time.to_string()),
+            ["30 seconds", "5 days", "2 weeks", "11 months", "1 year"]
+        );
+    }
+
+    #[test]
+    fn relative_time_timestamp() {
+        let time = RelativeTime {
+            unit: TimeUnit::Day,
+            amount: 2,
+        };
+        let reference = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+
+        assert_eq!(time.approximate_duration(), Duration::from_secs(2 * 24 * 60 * 60));
+        assert_eq!(
+            time.approximate_timestamp(reference),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 - 2 * 24 * 60 * 60))
+        );
+    }
+}
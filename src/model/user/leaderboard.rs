@@ -0,0 +1,173 @@
+use crate::{
+    model::{
+        time::RelativeTime,
+        user::{Color, IconType},
+    },
+    serde::Thunk,
+    GJFormat,
+};
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use variant_partial_eq::VariantPartialEq;
+
+/// Struct modelling a single entry of one of the player leaderboards returned by the `getGJScores`
+/// endpoint.
+///
+/// Apart from the [`LeaderboardEntry::rank`] and [`LeaderboardEntry::diamonds`], this contains the
+/// same data as a [`SearchedUser`](crate::model::user::searched::SearchedUser).
+#[derive(Debug, Clone, VariantPartialEq, Eq, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LeaderboardEntry<'a> {
+    /// This [`LeaderboardEntry`]'s name
+    #[dash(index = 1)]
+    pub name: Cow<'a, str>,
+
+    /// The [`LeaderboardEntry`]'s unique user ID
+    #[dash(index = 2)]
+    pub user_id: u64,
+
+    /// This [`LeaderboardEntry`]'s stars
+    #[dash(index = 3)]
+    pub stars: u32,
+
+    /// This [`LeaderboardEntry`]'s beaten demons
+    #[dash(index = 4)]
+    pub demons: u16,
+
+    /// The position of this [`LeaderboardEntry`] on the leaderboard it was retrieved from. The
+    /// first place has rank `1`.
+    ///
+    /// For the creator leaderboard, this is the rank by creator points, not by stars.
+    #[dash(index = 6)]
+    pub rank: u32,
+
+    /// This [`LeaderboardEntry`]'s creator points
+    #[dash(index = 8)]
+    pub creator_points: u16,
+
+    /// The index of the icon being displayed.
+    #[dash(index = 9)]
+    pub icon_index: u16,
+
+    /// This [`LeaderboardEntry`]'s primary color
+    ///
+    /// ## GD Internals:
+    /// See [`SearchedUser::primary_color`](crate::model::user::searched::SearchedUser::primary_color)
+    #[dash(index = 10)]
+    pub primary_color: Color,
+
+    /// This [`LeaderboardEntry`]'s secondary color
+    ///
+    /// ## GD Internals:
+    /// Same things as above apply
+    #[dash(index = 11)]
+    pub secondary_color: Color,
+
+    /// The amount of secret coins this [`LeaderboardEntry`] has collected.
+    #[dash(index = 13)]
+    pub secret_coins: u8,
+
+    /// The type of icon being displayed
+    #[dash(index = 14)]
+    pub icon_type: IconType,
+
+    /// Values indicating whether this [`LeaderboardEntry`] has glow activated or not.
+    #[dash(index = 15)]
+    #[dash(serialize_with = "crate::util::true_to_two")]
+    pub has_glow: bool,
+
+    /// The [`LeaderboardEntry`]'s unique account ID
+    #[dash(index = 16)]
+    pub account_id: u64,
+
+    /// The amount of user coins this [`LeaderboardEntry`] has collected.
+    #[dash(index = 17)]
+    pub user_coins: u16,
+
+    /// The amount of diamonds this [`LeaderboardEntry`] has collected. Unlike for user searches,
+    /// the servers provide this value for leaderboards.
+    #[dash(index = 46)]
+    pub diamonds: u32,
+
+    /// The number of moons this [`LeaderboardEntry`] has collected.
+    #[dash(index = 52)]
+    pub moons: u32,
+}
+
+impl<'de> GJFormat<'de> for LeaderboardEntry<'de> {
+    const DELIMITER: &'static str = ":";
+    const MAP_LIKE: bool = true;
+}
+
+/// Struct modelling a single entry of a level's leaderboard as returned by the `getGJLevelScores`
+/// and `getGJLevelScoresPlat` endpoints.
+#[derive(Debug, Clone, VariantPartialEq, Serialize, Deserialize, Dash, IntoOwned)]
+pub struct LevelScore<'a> {
+    /// The name of the player who set this [`LevelScore`]
+    #[dash(index = 1)]
+    pub name: Cow<'a, str>,
+
+    /// The unique user ID of the player who set this [`LevelScore`]
+    #[dash(index = 2)]
+    pub user_id: u64,
+
+    /// The score itself
+    ///
+    /// For classic levels, this is the percentage reached. For platformer levels (see
+    /// [`PlatformerLevelScoresRequest`](crate::request::level::PlatformerLevelScoresRequest)), this
+    /// is either the completion time in milliseconds or the amount of points collected, depending on
+    /// the [`PlatformerScoreMode`](crate::request::level::PlatformerScoreMode) of the request.
+    #[dash(index = 3)]
+    pub score: u32,
+
+    /// The position of this [`LevelScore`] on the leaderboard. The first place has rank `1`.
+    #[dash(index = 6)]
+    pub rank: u32,
+
+    /// The index of the icon being displayed.
+    #[dash(index = 9)]
+    pub icon_index: u16,
+
+    /// The primary color of the player who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// See [`SearchedUser::primary_color`](crate::model::user::searched::SearchedUser::primary_color)
+    #[dash(index = 10)]
+    pub primary_color: Color,
+
+    /// The secondary color of the player who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// Same things as above apply
+    #[dash(index = 11)]
+    pub secondary_color: Color,
+
+    /// The amount of coins collected in the run this [`LevelScore`] was set in
+    #[dash(index = 13)]
+    pub coins: u8,
+
+    /// The type of icon being displayed
+    #[dash(index = 14)]
+    pub icon_type: IconType,
+
+    /// Values indicating whether the player who set this [`LevelScore`] has glow activated or not.
+    #[dash(index = 15)]
+    #[dash(serialize_with = "crate::util::true_to_two")]
+    pub has_glow: bool,
+
+    /// The unique account ID of the player who set this [`LevelScore`]
+    #[dash(index = 16)]
+    pub account_id: u64,
+
+    /// How long ago this [`LevelScore`] was set
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `42` as a string such as `"3 weeks"`
+    #[dash(index = 42)]
+    pub time_since: Thunk<'a, RelativeTime>,
+}
+
+impl<'de> GJFormat<'de> for LevelScore<'de> {
+    const DELIMITER: &'static str = ":";
+    const MAP_LIKE: bool = true;
+}
@@ -1,18 +1,23 @@
+use dash_rs_derive::Dash;
 use serde::{Deserialize, Serialize};
 
+pub mod leaderboard;
 pub mod profile;
 pub mod searched;
 
 /// Enum representing the different types of moderator a user can be
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize, Dash)]
 pub enum ModLevel {
     /// User isn't a moderator
+    #[dash(value = 0)]
     None,
 
     /// User is a normal moderator
+    #[dash(value = 1)]
     Normal,
 
     /// User is an elder moderator
+    #[dash(value = 2)]
     Elder,
 
     /// Unknown or invalid value. This variant will be constructed if robtop ever adds more
@@ -21,73 +26,26 @@ pub enum ModLevel {
     Unknown(u8),
 }
 
-impl From<ModLevel> for u8 {
-    fn from(level: ModLevel) -> u8 {
-        match level {
-            ModLevel::None => 0,
-            ModLevel::Normal => 1,
-            ModLevel::Elder => 2,
-            ModLevel::Unknown(inner) => inner,
-        }
-    }
-}
-
-impl From<u8> for ModLevel {
-    fn from(i: u8) -> Self {
-        match i {
-            0 => ModLevel::None,
-            1 => ModLevel::Normal,
-            2 => ModLevel::Elder,
-            i => ModLevel::Unknown(i),
-        }
-    }
-}
-
-crate::into_conversion!(ModLevel, u8);
-
 /// The type of icon displayed next a user's comment of next to their search result
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Dash)]
 pub enum IconType {
+    #[dash(value = 0)]
     Cube,
+    #[dash(value = 1)]
     Ship,
+    #[dash(value = 2)]
     Ball,
+    #[dash(value = 3)]
     Ufo,
+    #[dash(value = 4)]
     Wave,
+    #[dash(value = 5)]
     Robot,
+    #[dash(value = 6)]
     Spider,
     Unknown(u8),
 }
 
-impl From<u8> for IconType {
-    fn from(i: u8) -> Self {
-        match i {
-            0 => IconType::Cube,
-            1 => IconType::Ship,
-            2 => IconType::Ball,
-            3 => IconType::Ufo,
-            4 => IconType::Wave,
-            5 => IconType::Robot,
-            6 => IconType::Spider,
-            i => IconType::Unknown(i),
-        }
-    }
-}
-
-impl From<IconType> for u8 {
-    fn from(mode: IconType) -> u8 {
-        match mode {
-            IconType::Cube => 0,
-            IconType::Ship => 1,
-            IconType::Ball => 2,
-            IconType::Ufo => 3,
-            IconType::Wave => 4,
-            IconType::Robot => 5,
-            IconType::Spider => 6,
-            IconType::Unknown(idx) => idx,
-        }
-    }
-}
-
 // Enum representing an in-game icon color
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Serialize, Deserialize)]
 pub enum Color {
@@ -203,4 +161,3 @@ impl From<Color> for u8 {
 }
 
 crate::into_conversion!(Color, u8);
-crate::into_conversion!(IconType, u8);
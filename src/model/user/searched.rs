@@ -2,7 +2,7 @@ use crate::{
     model::user::{Color, IconType},
     GJFormat,
 };
-use dash_rs_derive::Dash;
+use dash_rs_derive::{Dash, IntoOwned};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use variant_partial_eq::VariantPartialEq;
@@ -12,7 +12,7 @@ use variant_partial_eq::VariantPartialEq;
 /// Note that no field `diamonds` exists here. This is consistent with Geometry Dash's behavior, as
 /// the GD server exhibit a bug where they do not provide diamonds information, although the client
 /// has the UI for it.
-#[derive(Debug, Clone, VariantPartialEq, Eq, Serialize, Deserialize, Dash)]
+#[derive(Debug, Clone, VariantPartialEq, Eq, Serialize, Deserialize, Dash, IntoOwned)]
 pub struct SearchedUser<'a> {
     /// This [`SearchedUser`]'s name
     #[dash(index = 1)]
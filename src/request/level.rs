@@ -3,31 +3,48 @@ use crate::{
         level::{DemonRating, LevelLength, LevelRating},
         song::MainSong,
     },
-    request::{BaseRequest, GD_22, REQUEST_BASE_URL},
+    request::{generate_chk, BaseRequest, GD_22, REQUEST_BASE_URL},
 };
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
 
 pub const DOWNLOAD_LEVEL_ENDPOINT: &str = "downloadGJLevel22.php";
 pub const SEARCH_LEVEL_ENDPOINT: &str = "getGJLevels21.php";
+pub const DAILY_LEVEL_ENDPOINT: &str = "getGJDailyLevel.php";
+pub const LEVEL_SCORES_ENDPOINT: &str = "getGJLevelScores211.php";
+pub const PLATFORMER_LEVEL_SCORES_ENDPOINT: &str = "getGJLevelScoresPlat.php";
+
+/// The XOR key the game uses when computing the `chk` value of a [`LevelScoresRequest`]
+const LEVEL_SCORES_CHK_KEY: &str = "39673";
+
+/// The salt the game appends to the values hashed for the `chk` value of a [`LevelScoresRequest`]
+const LEVEL_SCORES_CHK_SALT: &str = "yPg6pUrtWn0J";
 
 /// Struct modelled after a request to `downloadGJLevel22.php`.
 ///
 /// In the Geometry Dash API, this endpoint is used to download a level from
 /// the servers and retrieve some additional information that isn't provided
 /// with the response to a [`LevelsRequest`]
-#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash)]
 pub struct LevelRequest<'a> {
     /// The base request data
-    #[serde(borrow)]
     pub base: BaseRequest<'a>,
 
     /// The ID of the level to download
     ///
     /// ## GD Internals:
     /// This field is called `levelID` in the boomlings API
-    #[serde(rename = "levelID")]
     pub level_id: u64,
 
+    /// The kind of periodically replaced level to download instead of the level with the ID
+    /// [`level_id`](LevelRequest::level_id), if any (see [`LevelRequest::daily`])
+    ///
+    /// ## GD Internals:
+    /// This is not a separate field in the boomlings API. Instead, the
+    /// [special level ID](DailyLevelType::special_level_id) of the level type is sent as
+    /// `levelID`. Since no special level ID is known for [`DailyLevelType::Unknown`] variants,
+    /// requests with such a level type cannot be serialized.
+    pub daily: Option<DailyLevelType>,
+
     /// Some weird field the Geometry Dash Client sends along
     ///
     /// ## GD Internals:
@@ -79,16 +96,107 @@ impl<'a> LevelRequest<'a> {
         LevelRequest {
             base: GD_22,
             level_id,
+            daily: None,
             inc: true,
             extra: false,
         }
     }
 
+    /// Constructs a new `LevelRequest` to retrieve the current daily, weekly or event level,
+    /// without needing to know its actual ID
+    ///
+    /// The response is a regular level download response, containing the level's actual ID. Use
+    /// a [`DailyLevelRequest`] to find out when the level will next be replaced.
+    ///
+    /// Returns [`None`] for [`DailyLevelType::Unknown`] variants, as no special level ID is known
+    /// for them.
+    pub const fn daily(level_type: DailyLevelType) -> Option<LevelRequest<'static>> {
+        match level_type.special_level_id() {
+            Some(_) => Some(LevelRequest {
+                daily: Some(level_type),
+                ..LevelRequest::new(0)
+            }),
+            None => None,
+        }
+    }
+
     pub fn to_url(&self) -> String {
         format!("{}{}", REQUEST_BASE_URL, DOWNLOAD_LEVEL_ENDPOINT)
     }
 }
 
+impl Serialize for LevelRequest<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct RawLevelRequest<'a> {
+            base: BaseRequest<'a>,
+            #[serde(rename = "levelID")]
+            level_id: i64,
+            inc: bool,
+            extra: bool,
+        }
+
+        let level_id = match self.daily {
+            None => self.level_id as i64,
+            Some(level_type) => level_type
+                .special_level_id()
+                .ok_or_else(|| serde::ser::Error::custom(format!("no special level ID is known for {:?}", level_type)))?,
+        };
+
+        RawLevelRequest {
+            base: self.base,
+            level_id,
+            inc: self.inc,
+            extra: self.extra,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for LevelRequest<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawLevelRequest<'a> {
+            #[serde(borrow)]
+            base: BaseRequest<'a>,
+            #[serde(rename = "levelID")]
+            level_id: i64,
+            inc: bool,
+            extra: bool,
+        }
+
+        let raw = RawLevelRequest::deserialize(deserializer)?;
+
+        let (level_id, daily) = if raw.level_id >= 0 {
+            (raw.level_id as u64, None)
+        } else {
+            match DailyLevelType::from_special_level_id(raw.level_id) {
+                Some(level_type) => (0, Some(level_type)),
+                None => {
+                    return Err(serde::de::Error::invalid_value(
+                        Unexpected::Signed(raw.level_id),
+                        &"a level ID or the special level ID of a daily, weekly or event level",
+                    ))
+                },
+            }
+        };
+
+        Ok(LevelRequest {
+            base: raw.base,
+            level_id,
+            daily,
+            inc: raw.inc,
+            extra: raw.extra,
+        })
+    }
+}
+
 impl ToString for LevelRequest<'_> {
     fn to_string(&self) -> String {
         super::to_string(self)
@@ -428,6 +536,540 @@ impl From<LevelRequestType> for i32 {
     }
 }
 
+/// Enum containing the different kinds of levels that are periodically replaced by the servers
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(from = "i32", into = "i32")]
+pub enum DailyLevelType {
+    /// The daily level, which is replaced every day
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests
+    #[default]
+    Daily,
+
+    /// The weekly demon, which is replaced every week
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests
+    Weekly,
+
+    /// The event level, which is only available for a limited time
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2` in requests
+    Event,
+
+    /// Unknown variant not yet mapped by dash-rs
+    Unknown(i32),
+}
+
+impl DailyLevelType {
+    /// The special level ID that can be used in a [`LevelRequest`] to download the current level of
+    /// this type. See also [`LevelRequest::daily`].
+    ///
+    /// ## GD Internals:
+    /// These are `-1` for the daily level, `-2` for the weekly demon and `-3` for the event level.
+    /// For unknown variants, no special level ID is known and `None` is returned.
+    pub const fn special_level_id(self) -> Option<i64> {
+        match self {
+            DailyLevelType::Daily => Some(-1),
+            DailyLevelType::Weekly => Some(-2),
+            DailyLevelType::Event => Some(-3),
+            DailyLevelType::Unknown(_) => None,
+        }
+    }
+
+    /// The level type whose [special level ID](DailyLevelType::special_level_id) is the given
+    /// value, if any
+    pub const fn from_special_level_id(special_level_id: i64) -> Option<DailyLevelType> {
+        match special_level_id {
+            -1 => Some(DailyLevelType::Daily),
+            -2 => Some(DailyLevelType::Weekly),
+            -3 => Some(DailyLevelType::Event),
+            _ => None,
+        }
+    }
+}
+
+impl From<i32> for DailyLevelType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DailyLevelType::Daily,
+            1 => DailyLevelType::Weekly,
+            2 => DailyLevelType::Event,
+            _ => DailyLevelType::Unknown(value),
+        }
+    }
+}
+
+impl From<DailyLevelType> for i32 {
+    fn from(level_type: DailyLevelType) -> Self {
+        match level_type {
+            DailyLevelType::Daily => 0,
+            DailyLevelType::Weekly => 1,
+            DailyLevelType::Event => 2,
+            DailyLevelType::Unknown(value) => value,
+        }
+    }
+}
+
+/// Struct modelled after a request to `getGJDailyLevel.php`
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve information about the current daily,
+/// weekly or event level, namely its index and the time left until it is replaced (see
+/// [`DailyLevel`](crate::model::level::daily::DailyLevel)). The level itself can be downloaded
+/// using [`LevelRequest::daily`].
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Hash)]
+pub struct DailyLevelRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// Whether to retrieve information about the daily, weekly or event level
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API and needs to be converted to an integer
+    #[serde(rename = "type")]
+    pub level_type: DailyLevelType,
+}
+
+impl From<DailyLevelType> for DailyLevelRequest<'_> {
+    fn from(level_type: DailyLevelType) -> Self {
+        DailyLevelRequest::new(level_type)
+    }
+}
+
+impl<'a> DailyLevelRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    /// Constructs a new `DailyLevelRequest` to retrieve information about the current level of the
+    /// given type
+    ///
+    /// Uses a default [`BaseRequest`]
+    pub const fn new(level_type: DailyLevelType) -> DailyLevelRequest<'static> {
+        DailyLevelRequest { base: GD_22, level_type }
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, DAILY_LEVEL_ENDPOINT)
+    }
+}
+
+impl ToString for DailyLevelRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+/// Enum containing the different leaderboards available for a single level
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(from = "i32", into = "i32")]
+pub enum LevelScoresType {
+    /// The scores of the requesting player's friends
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests. The servers identify the
+    /// requesting player by the `accountID` and `gjp2` fields.
+    Friends,
+
+    /// The best scores of all players
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests
+    #[default]
+    Top,
+
+    /// The best scores set during the current week
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2` in requests
+    Weekly,
+
+    /// Unknown variant not yet mapped by dash-rs
+    Unknown(i32),
+}
+
+impl From<i32> for LevelScoresType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => LevelScoresType::Friends,
+            1 => LevelScoresType::Top,
+            2 => LevelScoresType::Weekly,
+            _ => LevelScoresType::Unknown(value),
+        }
+    }
+}
+
+impl From<LevelScoresType> for i32 {
+    fn from(scores_type: LevelScoresType) -> Self {
+        match scores_type {
+            LevelScoresType::Friends => 0,
+            LevelScoresType::Top => 1,
+            LevelScoresType::Weekly => 2,
+            LevelScoresType::Unknown(value) => value,
+        }
+    }
+}
+
+/// Enum containing the ways platformer level leaderboards can be ranked
+///
+/// Used by [`PlatformerLevelScoresRequest`]s. Leaderboards of classic levels are always ranked by
+/// percentage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(from = "i32", into = "i32")]
+pub enum PlatformerScoreMode {
+    /// Rank by the fastest completion time
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests
+    #[default]
+    Time,
+
+    /// Rank by the most points collected
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests
+    Points,
+
+    /// Unknown variant not yet mapped by dash-rs
+    Unknown(i32),
+}
+
+impl From<i32> for PlatformerScoreMode {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => PlatformerScoreMode::Time,
+            1 => PlatformerScoreMode::Points,
+            _ => PlatformerScoreMode::Unknown(value),
+        }
+    }
+}
+
+impl From<PlatformerScoreMode> for i32 {
+    fn from(mode: PlatformerScoreMode) -> Self {
+        match mode {
+            PlatformerScoreMode::Time => 0,
+            PlatformerScoreMode::Points => 1,
+            PlatformerScoreMode::Unknown(value) => value,
+        }
+    }
+}
+
+/// Struct modelled after a request to `getGJLevelScores211.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the leaderboard of a single classic
+/// level as a list of [`LevelScore`](crate::model::user::leaderboard::LevelScore)s. Along with the
+/// request, the game client submits the requesting player's own progress on the level. Leaderboards
+/// of platformer levels are retrieved using [`PlatformerLevelScoresRequest`]s instead.
+///
+/// ## GD Internals:
+/// Most of the progress values are obfuscated by adding a constant to them, and the request is
+/// signed using a `chk` value computed from them. dash-rs does both when serializing this request.
+/// The anti-cheat values `s4`, `s5`, `s6` and `s11` are not sent.
+#[derive(Debug, Default, Clone, Copy, Hash)]
+pub struct LevelScoresRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The account ID of the requesting player, or `0` if the request is made anonymously
+    ///
+    /// ## GD Internals:
+    /// This field is called `accountID` in the boomlings API
+    pub account_id: u64,
+
+    /// The requesting player's `gjp2` (a salted hash of their password). Only required for
+    /// [`LevelScoresType::Friends`]
+    pub gjp2: Option<&'a str>,
+
+    /// The ID of the level whose leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    pub level_id: u64,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API
+    pub scores_type: LevelScoresType,
+
+    /// The requesting player's best percentage on this level
+    pub percent: u8,
+
+    /// The requesting player's attempts on this level
+    ///
+    /// ## GD Internals:
+    /// This value is sent twice, once as `s8` and once with `8354` added as `s1`
+    pub attempts: u32,
+
+    /// The requesting player's amount of clicks on this level
+    ///
+    /// ## GD Internals:
+    /// This value is sent with `3991` added as `s2`
+    pub clicks: u32,
+
+    /// The amount of seconds the requesting player spent in this level
+    ///
+    /// ## GD Internals:
+    /// This value is sent with `4085` added as `s3`
+    pub seconds: u32,
+
+    /// The amount of secret or user coins the requesting player collected in this level
+    ///
+    /// ## GD Internals:
+    /// This value is sent with `5819` added as `s9`
+    pub coins: u8,
+
+    /// The ID of the daily level or weekly demon if the level is currently one, and `0` otherwise
+    ///
+    /// ## GD Internals:
+    /// This value is sent as `s10`
+    pub timely_id: u32,
+
+    /// A random string the game client generates for each request
+    ///
+    /// ## GD Internals:
+    /// This value is sent as `s7`
+    pub seed: &'a str,
+}
+
+impl<'a> LevelScoresRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    const_setter!(account_id: u64);
+
+    const_setter!(gjp2: Option<&'a str>);
+
+    const_setter!(scores_type: LevelScoresType);
+
+    const_setter!(percent: u8);
+
+    const_setter!(attempts: u32);
+
+    const_setter!(clicks: u32);
+
+    const_setter!(seconds: u32);
+
+    const_setter!(coins: u8);
+
+    const_setter!(timely_id: u32);
+
+    const_setter!(seed: &'a str);
+
+    /// Constructs a new `LevelScoresRequest` to retrieve the top scores of the level with the given
+    /// ID, without submitting any progress
+    ///
+    /// Uses a default [`BaseRequest`]
+    pub const fn new(level_id: u64) -> LevelScoresRequest<'static> {
+        LevelScoresRequest {
+            base: GD_22,
+            account_id: 0,
+            gjp2: None,
+            level_id,
+            scores_type: LevelScoresType::Top,
+            percent: 0,
+            attempts: 0,
+            clicks: 0,
+            seconds: 0,
+            coins: 0,
+            timely_id: 0,
+            seed: "",
+        }
+    }
+
+    /// The `chk` value the servers use to validate this request
+    ///
+    /// ## GD Internals:
+    /// The hashed values are, in order, the account ID, level ID, percent, seconds, clicks,
+    /// attempts, percent (again), `100` minus the percent, the constant `1`, coins, timely ID and
+    /// seed.
+    pub fn chk(&self) -> String {
+        let values = [
+            self.account_id.to_string(),
+            self.level_id.to_string(),
+            self.percent.to_string(),
+            self.seconds.to_string(),
+            self.clicks.to_string(),
+            self.attempts.to_string(),
+            self.percent.to_string(),
+            100u8.saturating_sub(self.percent).to_string(),
+            "1".to_string(),
+            self.coins.to_string(),
+            self.timely_id.to_string(),
+            self.seed.to_string(),
+        ];
+
+        generate_chk(&values.each_ref().map(String::as_str), LEVEL_SCORES_CHK_SALT, LEVEL_SCORES_CHK_KEY)
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, LEVEL_SCORES_ENDPOINT)
+    }
+}
+
+impl From<u64> for LevelScoresRequest<'_> {
+    fn from(level_id: u64) -> Self {
+        LevelScoresRequest::new(level_id)
+    }
+}
+
+impl Serialize for LevelScoresRequest<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct RawLevelScoresRequest<'a> {
+            base: BaseRequest<'a>,
+            #[serde(rename = "accountID")]
+            account_id: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            gjp2: Option<&'a str>,
+            #[serde(rename = "levelID")]
+            level_id: u64,
+            #[serde(rename = "type")]
+            scores_type: LevelScoresType,
+            percent: u8,
+            s1: u32,
+            s2: u32,
+            s3: u32,
+            s7: &'a str,
+            s8: u32,
+            s9: u32,
+            s10: u32,
+            chk: String,
+        }
+
+        RawLevelScoresRequest {
+            base: self.base,
+            account_id: self.account_id,
+            gjp2: self.gjp2,
+            level_id: self.level_id,
+            scores_type: self.scores_type,
+            percent: self.percent,
+            s1: self.attempts.wrapping_add(8354),
+            s2: self.clicks.wrapping_add(3991),
+            s3: self.seconds.wrapping_add(4085),
+            s7: self.seed,
+            s8: self.attempts,
+            s9: (self.coins as u32).wrapping_add(5819),
+            s10: self.timely_id,
+            chk: self.chk(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl ToString for LevelScoresRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+/// Struct modelled after a request to `getGJLevelScoresPlat.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the leaderboard of a single
+/// platformer level as a list of [`LevelScore`](crate::model::user::leaderboard::LevelScore)s.
+/// Leaderboards of classic levels are retrieved using [`LevelScoresRequest`]s instead.
+///
+/// ## GD Internals:
+/// The game client also submits the requesting player's own progress on the level along with
+/// this request. dash-rs does not support submitting progress and only sends the values needed to
+/// retrieve the leaderboard.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Hash)]
+pub struct PlatformerLevelScoresRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// The account ID of the requesting player, or `0` if the request is made anonymously
+    ///
+    /// ## GD Internals:
+    /// This field is called `accountID` in the boomlings API
+    #[serde(rename = "accountID")]
+    pub account_id: u64,
+
+    /// The requesting player's `gjp2` (a salted hash of their password). Only required for
+    /// [`LevelScoresType::Friends`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gjp2: Option<&'a str>,
+
+    /// The ID of the level whose leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    #[serde(rename = "levelID")]
+    pub level_id: u64,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API
+    #[serde(rename = "type")]
+    pub scores_type: LevelScoresType,
+
+    /// Whether to rank the leaderboard by completion time or by points
+    pub mode: PlatformerScoreMode,
+}
+
+impl<'a> PlatformerLevelScoresRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    const_setter!(account_id: u64);
+
+    const_setter!(gjp2: Option<&'a str>);
+
+    const_setter!(scores_type: LevelScoresType);
+
+    const_setter!(mode: PlatformerScoreMode);
+
+    /// Constructs a new `PlatformerLevelScoresRequest` to retrieve the top times of the platformer
+    /// level with the given ID
+    ///
+    /// Uses a default [`BaseRequest`]
+    pub const fn new(level_id: u64) -> PlatformerLevelScoresRequest<'static> {
+        PlatformerLevelScoresRequest {
+            base: GD_22,
+            account_id: 0,
+            gjp2: None,
+            level_id,
+            scores_type: LevelScoresType::Top,
+            mode: PlatformerScoreMode::Time,
+        }
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, PLATFORMER_LEVEL_SCORES_ENDPOINT)
+    }
+}
+
+impl From<u64> for PlatformerLevelScoresRequest<'_> {
+    fn from(level_id: u64) -> Self {
+        PlatformerLevelScoresRequest::new(level_id)
+    }
+}
+
+impl ToString for PlatformerLevelScoresRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SongFilter {
     #[serde(rename = "song")]
@@ -596,7 +1238,7 @@ impl ToString for LevelsRequest<'_> {
 
 /// Newtype struct for [`DemonRating`] to implement robtop's serialization for requests on
 #[derive(Debug, Clone, Copy, Hash)]
-struct DemonFilter(DemonRating);
+pub(super) struct DemonFilter(pub(super) DemonRating);
 
 impl Serialize for DemonFilter {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
@@ -625,23 +1267,13 @@ impl Serialize for LengthFilter {
     where
         S: Serializer,
     {
-        let numerical_value = match self.0 {
-            LevelLength::Unknown(unknown) => unknown,
-            LevelLength::Tiny => 0,
-            LevelLength::Short => 1,
-            LevelLength::Medium => 2,
-            LevelLength::Long => 3,
-            LevelLength::ExtraLong => 4,
-            LevelLength::Platformer => 5,
-        };
-
-        serializer.serialize_i32(numerical_value)
+        serializer.serialize_i32(self.0.into())
     }
 }
 
 /// Newtype struct for [`LevelRating`] to implement robtop's serialization for requests on
 #[derive(Debug, Clone, Copy, Hash)]
-struct RatingFilter(LevelRating);
+pub(super) struct RatingFilter(pub(super) LevelRating);
 
 impl Serialize for RatingFilter {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
@@ -669,9 +1301,40 @@ impl Serialize for RatingFilter {
 mod tests {
     use crate::{
         model::level::LevelLength,
-        request::level::{CompletionFilter, LevelRequestType, LevelsRequest, SearchFilters},
+        request::level::{
+            CompletionFilter, DailyLevelRequest, DailyLevelType, LevelRequest, LevelRequestType, LevelScoresRequest, LevelScoresType,
+            LevelsRequest, PlatformerLevelScoresRequest, PlatformerScoreMode, SearchFilters,
+        },
     };
 
+    #[test]
+    fn serialize_daily_level_requests() {
+        assert_eq!(
+            super::super::to_string(DailyLevelRequest::new(DailyLevelType::Weekly)),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&type=1"
+        );
+        assert_eq!(
+            super::super::to_string(LevelRequest::daily(DailyLevelType::Event).unwrap()),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&levelID=-3&inc=1&extra=0"
+        );
+        assert!(LevelRequest::daily(DailyLevelType::Unknown(3)).is_none());
+    }
+
+    #[test]
+    fn daily_level_request_round_trip() {
+        let json = serde_json::to_string(&LevelRequest::daily(DailyLevelType::Weekly).unwrap()).unwrap();
+        let request: LevelRequest = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(request.level_id, 0);
+        assert_eq!(request.daily, Some(DailyLevelType::Weekly));
+
+        let json = serde_json::to_string(&LevelRequest::new(10565740)).unwrap();
+        let request: LevelRequest = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(request.level_id, 10565740);
+        assert_eq!(request.daily, None);
+    }
+
     #[test]
     fn serialize_levels_request() {
         let request =
@@ -692,4 +1355,43 @@ mod tests {
              22031889,22390740,22243264,21923305)&onlyCompleted=0&uncompleted=1"
         );
     }
+
+    #[test]
+    fn serialize_level_scores_request() {
+        let request = LevelScoresRequest::new(10565740)
+            .scores_type(LevelScoresType::Weekly)
+            .percent(100)
+            .attempts(37)
+            .clicks(540)
+            .seconds(120)
+            .coins(3)
+            .seed("abcdefghij");
+
+        assert_eq!(
+            super::super::to_string(request),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&accountID=0&levelID=10565740&type=2&percent=100&s1=8391&s2=4531&\
+             s3=4205&s7=abcdefghij&s8=37&s9=5822&s10=0&chk=BlxVAgVXAAJUVgdcBwAGAFwOD1ALWgdWA1AKAVRQAg5TAwMHDlBVCw=="
+        );
+
+        let request = LevelScoresRequest::new(10565740)
+            .attempts(u32::MAX)
+            .clicks(u32::MAX)
+            .seconds(u32::MAX);
+
+        assert!(super::super::to_string(request).contains("&s1=8353&s2=3990&s3=4084&"));
+    }
+
+    #[test]
+    fn serialize_platformer_level_scores_request() {
+        let request = PlatformerLevelScoresRequest::new(97128491)
+            .account_id(4322668)
+            .gjp2(Some("gjp2"))
+            .scores_type(LevelScoresType::Friends)
+            .mode(PlatformerScoreMode::Points);
+
+        assert_eq!(
+            super::super::to_string(request),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&accountID=4322668&gjp2=gjp2&levelID=97128491&type=0&mode=1"
+        );
+    }
 }
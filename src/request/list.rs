@@ -0,0 +1,184 @@
+//! Module containing request definitions for retrieving level lists
+
+use crate::{
+    model::level::{DemonRating, LevelRating},
+    request::{
+        level::{DemonFilter, LevelRequestType, RatingFilter},
+        BaseRequest, REQUEST_BASE_URL,
+    },
+};
+use serde::Serialize;
+
+pub const SEARCH_LEVEL_LISTS_ENDPOINT: &str = "getGJLevelLists.php";
+
+/// Struct modelled after a request to `getGJLevelLists.php`
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve a list of
+/// [`LevelList`](crate::model::list::LevelList)s matching the specified criteria, along with their
+/// [`Creator`](crate::model::creator::Creator)s
+///
+/// ## GD Internals:
+/// The endpoint understands the same values for `type` as `getGJLevels21.php`, although not all of
+/// them make sense for lists (e.g. [`LevelRequestType::MapPack`]).
+#[derive(Debug, Default, Clone, Serialize, Hash)]
+pub struct LevelListsRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// The type of list of level lists to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API and needs to be
+    /// converted to an integer
+    #[serde(rename = "type")]
+    pub request_type: LevelRequestType,
+
+    /// A search string to filter the level lists by
+    ///
+    /// This value is ignored unless [`LevelListsRequest::request_type`] is set to
+    /// [`LevelRequestType::Search`] or [`LevelRequestType::User`]
+    ///
+    /// ## GD Internals:
+    /// This field is called `str` in the boomlings API
+    #[serde(rename = "str")]
+    pub search_string: &'a str,
+
+    /// A list of list difficulties to filter by.
+    ///
+    /// To filter by any demon, add [`LevelRating::Demon`] with any arbitrary [`DemonRating`] value.
+    ///
+    /// `ratings` and [`LevelListsRequest::demon_rating`] are mutually exlusive.
+    ///
+    /// This value is ignored unless [`LevelListsRequest::request_type`] is set to
+    /// [`LevelRequestType::Search`]
+    ///
+    /// ## GD Internals:
+    /// This field is called `diff` in the boomlings API and needs to be
+    /// converted to a comma separated list of integers, or a single dash
+    /// (`-`) if filtering by difficulty isn't wanted.
+    #[serde(rename = "diff")]
+    ratings: Vec<RatingFilter>,
+
+    /// Optionally, a single demon rating to filter by. To filter by any demon
+    /// rating, use [`LevelListsRequest::ratings`]
+    ///
+    /// `demon_rating` and `ratings` are mutually exlusive.
+    ///
+    /// This value is ignored unless [`LevelListsRequest::request_type`] is set to
+    /// [`LevelRequestType::Search`]
+    ///
+    /// ## GD Internals:
+    /// This field is called `demonFilter` in the boomlings API and needs to be
+    /// converted to an integer. If filtering by demon rating isn't wanted,
+    /// the value has to be omitted from the request.
+    #[serde(rename = "demonFilter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    demon_rating: Option<DemonFilter>,
+
+    /// The page of results to retrieve
+    pub page: u32,
+
+    /// Some weird value the Geometry Dash client sends along
+    pub total: i32,
+
+    /// Only retrieve star rated level lists
+    ///
+    /// This value is ignored unless [`LevelListsRequest::request_type`] is set to
+    /// [`LevelRequestType::Search`]
+    ///
+    /// ## GD Internals:
+    /// This field is called `star` in the boomlings API and needs to be
+    /// converted to an integer
+    #[serde(rename = "star")]
+    pub rated: bool,
+}
+
+impl<'a> LevelListsRequest<'a> {
+    const_setter!(page: u32);
+
+    const_setter!(total: i32);
+
+    const_setter!(request_type: LevelRequestType);
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, SEARCH_LEVEL_LISTS_ENDPOINT)
+    }
+
+    pub fn with_base(base: BaseRequest<'a>) -> Self {
+        LevelListsRequest {
+            base,
+            ..Default::default()
+        }
+    }
+
+    /// Turns this request into a [`LevelRequestType::Search`]-type request, with the search
+    /// parameter set to the given string
+    pub const fn search(mut self, search_string: &'a str) -> Self {
+        self.search_string = search_string;
+        self.request_type = LevelRequestType::Search;
+        self
+    }
+
+    /// Turns on filtering by difficulty (if not already on) and adds the given level rating to the
+    /// list of difficulties to include in the search results
+    ///
+    /// Passing [`LevelRating::Demon`] here will turn on filtering by _any_ demon difficulty. The
+    /// filter `demon_rating` for specific demon difficulties is reset to `None` when this method is
+    /// called, as these modes are mutually exclusive.
+    pub fn with_rating(mut self, rating: LevelRating) -> Self {
+        self.demon_rating = None;
+        self.ratings.push(RatingFilter(rating));
+        self
+    }
+
+    /// Turns on filtering by demon difficulty
+    ///
+    /// Resets any [`LevelRating`] filters set beforehand, as these modes are mutually exclusive.
+    pub fn demon_rating(mut self, demon_rating: DemonRating) -> Self {
+        self.ratings.clear();
+        self.demon_rating = Some(DemonFilter(demon_rating));
+        self
+    }
+
+    /// Limit search results to star rated level lists
+    pub const fn rated(mut self) -> Self {
+        self.rated = true;
+        self
+    }
+}
+
+impl ToString for LevelListsRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::{DemonRating, LevelRating},
+        request::{level::LevelRequestType, list::LevelListsRequest},
+    };
+
+    #[test]
+    fn serialize_level_lists_request() {
+        let request = LevelListsRequest::default()
+            .request_type(LevelRequestType::MostLiked)
+            .with_rating(LevelRating::Insane)
+            .with_rating(LevelRating::Demon(DemonRating::Hard))
+            .rated();
+
+        assert_eq!(
+            super::super::to_string(request),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&type=2&str=&diff=5,-2&page=0&total=0&star=1"
+        );
+
+        let request = LevelListsRequest::default().search("demons").demon_rating(DemonRating::Extreme);
+
+        assert_eq!(
+            super::super::to_string(request),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&type=0&str=demons&diff=-&demonFilter=5&page=0&total=0&star=0"
+        );
+    }
+}
@@ -5,7 +5,8 @@
 //! making/proxying requests for the boomlings servers seems rather useless to me, as they already
 //! contain a lot of boomlings-specific fields.
 
-use crate::{model::GameVersion, serde::RequestSerializer};
+use crate::{model::GameVersion, serde::RequestSerializer, util};
+use base64::{engine::general_purpose::URL_SAFE, Engine};
 use serde::{Deserialize, Serialize};
 
 macro_rules! const_setter {
@@ -42,6 +43,8 @@ macro_rules! const_setter {
 
 pub mod comment;
 pub mod level;
+pub mod list;
+pub mod pack;
 pub mod user;
 
 pub const REQUEST_BASE_URL: &str = "https://www.boomlings.com/database/";
@@ -122,3 +125,25 @@ pub(crate) fn to_string<S: Serialize>(request: S) -> String {
 
     String::from_utf8(output).unwrap()
 }
+
+/// Computes the `chk` value some endpoints require to verify that a request was made by a genuine
+/// Geometry Dash client
+///
+/// ## GD Internals:
+/// The check value is computed by concatenating all `values` and the endpoint specific `salt`,
+/// taking the hex encoded SHA-1 digest of the result, XOR-ing it with the endpoint specific `key`
+/// and finally urlsafe base64 encoding it.
+pub(crate) fn generate_chk(values: &[&str], salt: &str, key: &str) -> String {
+    let mut hasher = sha1_smol::Sha1::new();
+
+    for value in values {
+        hasher.update(value.as_bytes());
+    }
+    hasher.update(salt.as_bytes());
+
+    let mut digest = hasher.digest().to_string().into_bytes();
+
+    util::cyclic_xor(&mut digest[..], key);
+
+    URL_SAFE.encode(digest)
+}
@@ -0,0 +1,143 @@
+//! Module containing request definitions for retrieving map packs and gauntlets
+
+use crate::request::{BaseRequest, GD_22, REQUEST_BASE_URL};
+use serde::{Deserialize, Serialize};
+
+pub const MAP_PACKS_ENDPOINT: &str = "getGJMapPacks21.php";
+pub const GAUNTLETS_ENDPOINT: &str = "getGJGauntlets21.php";
+
+/// Struct modelled after a request to `getGJMapPacks21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve a page of
+/// [`MapPack`](crate::model::pack::MapPack)s. Each page contains at most 10 map packs.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Hash)]
+pub struct MapPacksRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// The page of map packs to retrieve
+    pub page: u32,
+}
+
+impl From<u32> for MapPacksRequest<'_> {
+    fn from(page: u32) -> Self {
+        MapPacksRequest::new(page)
+    }
+}
+
+impl<'a> MapPacksRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    const_setter! {
+        /// Sets the page of map packs to retrieve
+        ///
+        /// Allows builder-style creation of requests
+        page: u32
+    }
+
+    /// Constructs a new `MapPacksRequest` to retrieve the given page of map packs
+    ///
+    /// Uses a default [`BaseRequest`]
+    pub const fn new(page: u32) -> MapPacksRequest<'static> {
+        MapPacksRequest { base: GD_22, page }
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, MAP_PACKS_ENDPOINT)
+    }
+}
+
+impl ToString for MapPacksRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+/// Struct modelled after a request to `getGJGauntlets21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the list of all
+/// [`Gauntlet`](crate::model::pack::Gauntlet)s. This endpoint does not paginate its results.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash)]
+pub struct GauntletsRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// Whether to also retrieve the gauntlets added in Geometry Dash 2.2
+    ///
+    /// ## GD Internals:
+    /// This field is called `special` in the boomlings API and needs to be converted to an
+    /// integer. If not set, the servers only return the gauntlets that existed in Geometry Dash
+    /// 2.1.
+    pub special: bool,
+}
+
+impl Default for GauntletsRequest<'static> {
+    fn default() -> Self {
+        GauntletsRequest::new()
+    }
+}
+
+impl<'a> GauntletsRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    const_setter! {
+        /// Sets the value of the `special` field
+        ///
+        /// Allows builder-style creation of requests
+        special: bool
+    }
+
+    /// Constructs a new `GauntletsRequest` to retrieve all gauntlets
+    ///
+    /// Uses a default [`BaseRequest`] and sets `special` to `true`, as does the Geometry Dash 2.2
+    /// client
+    pub const fn new() -> GauntletsRequest<'static> {
+        GauntletsRequest {
+            base: GD_22,
+            special: true,
+        }
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, GAUNTLETS_ENDPOINT)
+    }
+}
+
+impl ToString for GauntletsRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::request::pack::{GauntletsRequest, MapPacksRequest};
+
+    #[test]
+    fn serialize_map_packs_request() {
+        assert_eq!(
+            super::super::to_string(MapPacksRequest::new(2)),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&page=2"
+        );
+    }
+
+    #[test]
+    fn serialize_gauntlets_request() {
+        assert_eq!(
+            super::super::to_string(GauntletsRequest::new()),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&special=1"
+        );
+    }
+}
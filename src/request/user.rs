@@ -4,10 +4,11 @@ use crate::{
     model::creator::Creator,
     request::{BaseRequest, GD_22, REQUEST_BASE_URL},
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 pub const GET_USER_ENDPOINT: &str = "getGJUserInfo20.php";
 pub const SEARCH_USER_ENDPOINT: &str = "getGJUsers20.php";
+pub const LEADERBOARD_ENDPOINT: &str = "getGJScores20.php";
 
 /// Struct modelled after a request to `getGJUserInfo20.php`.
 ///
@@ -119,3 +120,168 @@ impl ToString for UserSearchRequest<'_> {
         super::to_string(self)
     }
 }
+
+/// Enum containing the different player leaderboards the Geometry Dash servers provide
+///
+/// ## GD Internals:
+/// Unlike most enums in requests, these are represented by strings
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum LeaderboardType {
+    /// The global top players, ordered by stars
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"top"` in requests
+    #[default]
+    Top,
+
+    /// The players around the requesting player's own position on the global leaderboard
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"relative"` in requests. The servers identify the
+    /// requesting player by the [`account_id`](LeaderboardRequest::account_id) and
+    /// [`gjp2`](LeaderboardRequest::gjp2) fields of the request.
+    Relative,
+
+    /// The top creators, ordered by creator points
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"creators"` in requests
+    Creators,
+
+    /// The requesting player's friends
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"friends"` in requests. As for
+    /// [`LeaderboardType::Relative`], the requesting player needs to be identified.
+    Friends,
+}
+
+impl Serialize for LeaderboardType {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            LeaderboardType::Top => "top",
+            LeaderboardType::Relative => "relative",
+            LeaderboardType::Creators => "creators",
+            LeaderboardType::Friends => "friends",
+        })
+    }
+}
+
+/// Struct modelled after a request to `getGJScores20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the player leaderboards as a list of
+/// [`LeaderboardEntry`](crate::model::user::leaderboard::LeaderboardEntry)s
+#[derive(Debug, Default, Clone, Copy, Serialize, Hash)]
+pub struct LeaderboardRequest<'a> {
+    /// The base request data
+    #[serde(borrow)]
+    pub base: BaseRequest<'a>,
+
+    /// The account ID of the requesting player, or `0` if the request is made anonymously
+    ///
+    /// ## GD Internals:
+    /// This field is called `accountID` in the boomlings API
+    #[serde(rename = "accountID")]
+    pub account_id: u64,
+
+    /// The requesting player's `gjp2` (a salted hash of their password). Only required for
+    /// [`LeaderboardType::Relative`] and [`LeaderboardType::Friends`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gjp2: Option<&'a str>,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API
+    #[serde(rename = "type")]
+    pub leaderboard_type: LeaderboardType,
+
+    /// The amount of players to retrieve. The Geometry Dash client always requests `100`, which is
+    /// also the maximum the servers will return.
+    pub count: u32,
+}
+
+impl<'a> LeaderboardRequest<'a> {
+    const_setter! {
+        /// Sets the [`BaseRequest`] to be used
+        ///
+        /// Allows builder-style creation of requests
+        base[with_base]: BaseRequest<'a>
+    }
+
+    const_setter!(account_id: u64);
+
+    const_setter!(gjp2: Option<&'a str>);
+
+    const_setter! {
+        /// Sets the leaderboard to retrieve
+        ///
+        /// Allows builder-style creation of requests
+        leaderboard_type: LeaderboardType
+    }
+
+    const_setter! {
+        /// Sets the amount of players to retrieve
+        ///
+        /// Allows builder-style creation of requests
+        count: u32
+    }
+
+    /// Constructs a new `LeaderboardRequest` to retrieve the top 100 players of the given
+    /// leaderboard
+    ///
+    /// Uses a default [`BaseRequest`]
+    pub const fn new(leaderboard_type: LeaderboardType) -> LeaderboardRequest<'static> {
+        LeaderboardRequest {
+            base: GD_22,
+            account_id: 0,
+            gjp2: None,
+            leaderboard_type,
+            count: 100,
+        }
+    }
+
+    pub fn to_url(&self) -> String {
+        format!("{}{}", REQUEST_BASE_URL, LEADERBOARD_ENDPOINT)
+    }
+}
+
+impl From<LeaderboardType> for LeaderboardRequest<'_> {
+    fn from(leaderboard_type: LeaderboardType) -> Self {
+        LeaderboardRequest::new(leaderboard_type)
+    }
+}
+
+impl ToString for LeaderboardRequest<'_> {
+    fn to_string(&self) -> String {
+        super::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::request::user::{LeaderboardRequest, LeaderboardType};
+
+    #[test]
+    fn serialize_leaderboard_request() {
+        assert_eq!(
+            super::super::to_string(LeaderboardRequest::new(LeaderboardType::Top)),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&accountID=0&type=top&count=100"
+        );
+        assert_eq!(
+            super::super::to_string(LeaderboardRequest::new(LeaderboardType::Creators).count(50)),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&accountID=0&type=creators&count=50"
+        );
+        assert_eq!(
+            super::super::to_string(
+                LeaderboardRequest::new(LeaderboardType::Relative)
+                    .account_id(4322668)
+                    .gjp2(Some("gjp2"))
+            ),
+            "gameVersion=22&binaryVersion=38&secret=Wmfd2893gb7&accountID=4322668&gjp2=gjp2&type=relative&count=100"
+        );
+    }
+}
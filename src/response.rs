@@ -10,9 +10,15 @@ use crate::{
             profile::ProfileComment,
         },
         creator::Creator,
-        level::{Level, ListedLevel},
+        level::{daily::DailyLevel, Level, ListedLevel},
+        list::{LevelList, ListedLevelList},
+        pack::{Gauntlet, MapPack},
         song::NewgroundsSong,
-        user::{profile::Profile, searched::SearchedUser},
+        user::{
+            leaderboard::{LeaderboardEntry, LevelScore},
+            profile::Profile,
+            searched::SearchedUser,
+        },
     },
     serde::GJFormat,
     DeError,
@@ -121,6 +127,86 @@ pub fn parse_download_gj_level_response(response: &str) -> Result<Level, Respons
     Ok(Level::from_gj_str(section!(sections))?)
 }
 
+pub fn parse_get_gj_level_lists_response(response: &str) -> Result<Vec<ListedLevelList<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    let mut sections = response.split('#');
+
+    let lists = section!(sections);
+    let creators = section!(sections)
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(Creator::from_gj_str)
+        .collect::<Result<Vec<Creator>, _>>()?;
+
+    lists
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(|fragment| {
+            let list: LevelList = LevelList::from_gj_str(fragment)?;
+            let creator = creators.iter().find(|creator| creator.account_id == Some(list.creator)).cloned();
+
+            Ok(LevelList {
+                list_id: list.list_id,
+                name: list.name,
+                description: list.description,
+                version: list.version,
+                difficulty: list.difficulty,
+                downloads: list.downloads,
+                likes: list.likes,
+                featured: list.featured,
+                uploaded: list.uploaded,
+                updated: list.updated,
+                creator,
+                levels: list.levels,
+                reward: list.reward,
+                reward_threshold: list.reward_threshold,
+            })
+        })
+        .collect::<Result<_, _>>()
+}
+
+pub fn parse_get_gj_daily_level_response(response: &str) -> Result<DailyLevel, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    Ok(DailyLevel::from_gj_str(response)?)
+}
+
+pub fn parse_get_gj_map_packs_response(response: &str) -> Result<Vec<MapPack<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    let mut sections = response.split('#');
+
+    // The remaining sections contain the pagination info (total amount of map packs, offset and page
+    // size, separated by ':') and a hash, neither of which we care about
+    section!(sections)
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(|fragment| Ok(MapPack::from_gj_str(fragment)?))
+        .collect()
+}
+
+pub fn parse_get_gj_gauntlets_response(response: &str) -> Result<Vec<Gauntlet<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    let mut sections = response.split('#');
+
+    // The remaining section is a hash, which we do not care about
+    section!(sections)
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(|fragment| Ok(Gauntlet::from_gj_str(fragment)?))
+        .collect()
+}
+
 pub fn parse_get_gj_user_info_response(response: &str) -> Result<Profile, ResponseError> {
     if response == "-1" {
         return Err(ResponseError::NotFound);
@@ -142,6 +228,30 @@ pub fn parse_get_gj_users_response(response: &str) -> Result<SearchedUser, Respo
     Ok(SearchedUser::from_gj_str(section!(sections))?)
 }
 
+pub fn parse_get_gj_scores_response(response: &str) -> Result<Vec<LeaderboardEntry<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    response
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(|fragment| Ok(LeaderboardEntry::from_gj_str(fragment)?))
+        .collect()
+}
+
+pub fn parse_get_gj_level_scores_response(response: &str) -> Result<Vec<LevelScore<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound);
+    }
+
+    response
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(|fragment| Ok(LevelScore::from_gj_str(fragment)?))
+        .collect()
+}
+
 pub fn parse_get_gj_comments_response(response: &str) -> Result<Vec<LevelComment>, ResponseError> {
     if response == "-1" {
         return Err(ResponseError::NotFound);
@@ -0,0 +1,166 @@
+//! Module for reading and writing `.gmd` and `.gmdl` level files
+//!
+//! These are the formats levels are commonly shared in outside of the game (e.g. by GDShare). A
+//! `.gmd` file is an unencrypted plist document whose root dictionary is a single level in the
+//! format of [`LocalLevel`]. A `.gmdl` file contains multiple levels, stored as the entries `k_0`,
+//! `k_1`, ... of its root dictionary, the same way the game stores arrays.
+
+use std::borrow::Cow;
+
+use thiserror::Error;
+
+use crate::{
+    model::level::local::LocalLevel,
+    serde::{PlistDict, PlistError, PlistValue, ARRAY_MARKER},
+    DeError, PlistFormat, SerError,
+};
+
+/// The key identifying the type of a plist dictionary
+///
+/// ## GD Internals:
+/// Dictionaries representing game objects (such as levels) contain the key `kCEK`, whose value
+/// identifies the kind of object stored. Levels have the value `4`.
+const OBJECT_TYPE_KEY: &str = "kCEK";
+
+/// The value of [`OBJECT_TYPE_KEY`] for levels
+const LEVEL_OBJECT_TYPE: i64 = 4;
+
+#[derive(Debug, Error)]
+pub enum GmdError<'a> {
+    /// The file was not a valid plist document
+    #[error("{0}")]
+    Xml(#[from] PlistError),
+
+    /// A deserializer error occurred while processing some level contained in the file
+    #[error("{0}")]
+    De(DeError<'a>), // cannot use #[from] here due to non-'static lifetime
+
+    /// A serializer error occurred while writing some level
+    #[error("{0}")]
+    Ser(#[from] SerError),
+
+    /// The file did not have the expected structure (e.g. a `.gmdl` file containing something that
+    /// isn't a level)
+    #[error("unexpected format")]
+    UnexpectedFormat,
+}
+
+impl<'a> From<DeError<'a>> for GmdError<'a> {
+    fn from(err: DeError<'a>) -> Self {
+        GmdError::De(err)
+    }
+}
+
+/// Reads the level stored in the given `.gmd` file
+///
+/// The returned level borrows from the given XML.
+pub fn parse_gmd(xml: &str) -> Result<LocalLevel<'_>, GmdError<'_>> {
+    let root = PlistDict::from_xml(xml)?;
+
+    Ok(LocalLevel::from_plist_dict(&root)?)
+}
+
+/// Writes the given level as a `.gmd` file
+///
+/// All keys of the level are written, including the ones dash-rs does not model (see
+/// [`LocalLevel::unknown_fields`]).
+pub fn write_gmd(level: &LocalLevel) -> Result<String, GmdError<'static>> {
+    Ok(level_dict(level)?.to_xml())
+}
+
+/// Reads all levels stored in the given `.gmdl` file, in the order they appear in the file
+///
+/// The returned levels borrow from the given XML.
+pub fn parse_gmdl(xml: &str) -> Result<Vec<LocalLevel<'_>>, GmdError<'_>> {
+    let root = PlistDict::from_xml(xml)?;
+
+    root.iter()
+        .filter(|(key, _)| *key != ARRAY_MARKER)
+        .map(|(_, value)| match value {
+            PlistValue::Dict(_) => Ok(LocalLevel::from_plist(value)?),
+            _ => Err(GmdError::UnexpectedFormat),
+        })
+        .collect()
+}
+
+/// Writes the given levels as a `.gmdl` file
+pub fn write_gmdl(levels: &[LocalLevel]) -> Result<String, GmdError<'static>> {
+    let mut root = PlistDict::new();
+
+    root.insert(ARRAY_MARKER, PlistValue::Bool(true));
+
+    for (idx, level) in levels.iter().enumerate() {
+        root.insert(format!("k_{}", idx), PlistValue::Dict(level_dict(level)?));
+    }
+
+    Ok(root.to_xml())
+}
+
+fn level_dict(level: &LocalLevel) -> Result<PlistDict<'static>, SerError> {
+    let mut dict = match level.to_plist()? {
+        PlistValue::Dict(dict) => dict,
+        _ => unreachable!(),
+    };
+
+    // LocalLevel does not model the object type, as it is the same for all levels. Levels read from
+    // a plist keep it in their unknown fields, all others (e.g. converted downloaded levels) lack it.
+    let object_type = dict.remove(OBJECT_TYPE_KEY).unwrap_or(PlistValue::Integer(LEVEL_OBJECT_TYPE));
+
+    dict.0.insert(0, (Cow::Borrowed(OBJECT_TYPE_KEY), object_type));
+
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::{Level, LevelData},
+        savefile::{
+            gmd::{parse_gmd, parse_gmdl, write_gmd, write_gmdl},
+            PlistDict, PlistValue,
+        },
+    };
+
+    const GMD: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>kCEK</k><i>4</i><k>k2</k><s>Test Level</s><k>k4</k><s>H4sIAAAAAAAAAyXFQQ3AMAwEQUL38Nq1FCmvYiiAAxAKBd9U-cysp4bwFU6T7XJ2G055KmP6Zzg8zKt1U4qJUIpWbeYHmESMV00AAAA=</s><k>k5</k><s>stadust</s><k>k13</k><t /><k>k21</k><i>2</i><k>k50</k><i>35</i></dict></plist>"#;
+
+    #[test]
+    fn read_gmd() {
+        let level = parse_gmd(GMD).unwrap();
+
+        assert_eq!(level.name, "Test Level");
+        assert_eq!(level.level_id, None);
+
+        let level = Level::<LevelData>::from(level);
+        let objects = level.level_data.level_data.into_processed().unwrap();
+
+        assert_eq!(objects.objects.len(), 1);
+        assert!(objects.length_in_seconds() > 0.0);
+    }
+
+    #[test]
+    fn write_gmd_round_trip() {
+        let level = parse_gmd(GMD).unwrap();
+        let written = write_gmd(&level).unwrap();
+
+        assert_eq!(PlistDict::from_xml(&written).unwrap(), PlistDict::from_xml(GMD).unwrap());
+        assert_eq!(parse_gmd(&written).unwrap(), level);
+    }
+
+    #[test]
+    fn gmdl_round_trip() {
+        let level = parse_gmd(GMD).unwrap();
+        let written = write_gmdl(&[parse_gmd(GMD).unwrap(), parse_gmd(GMD).unwrap()]).unwrap();
+        let levels = parse_gmdl(&written).unwrap();
+
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0], level);
+        assert_eq!(levels[1], level);
+
+        let root = PlistDict::from_xml(&written).unwrap();
+        let expected = PlistValue::Dict(PlistDict::from_xml(GMD).unwrap());
+
+        assert_eq!(root.get("k_0"), Some(&expected));
+        assert_eq!(root.get("k_1"), Some(&expected));
+        assert!(parse_gmdl(GMD).is_err());
+    }
+}
@@ -0,0 +1,364 @@
+//! Module containing a model of the game manager save file, `CCGameManager.dat`, which holds the
+//! player's statistics, icon selection, settings and progress
+
+use std::{borrow::Cow, convert::TryFrom, str::FromStr};
+
+use dash_rs_derive::{Dash, IntoOwned};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    model::user::Color,
+    request::level::CompletionFilter,
+    savefile::{PlistDict, PlistValue, SaveFileError},
+    DeError, PlistFormat,
+};
+
+/// Prefix of the keys in `GS_completed` that mark an online level as completed
+const COMPLETED_ONLINE_LEVEL_PREFIX: &str = "c_";
+
+/// The contents of `CCGameManager.dat`
+///
+/// Only a small fraction of the data stored in the game manager is understood by dash-rs. The
+/// well-known parts can be retrieved via typed accessors, while everything is available through the
+/// underlying dictionary (see [`GameManager::get`] and [`GameManager::root`]), which is kept as-is.
+/// This means that writing back a game manager never loses any data.
+#[derive(Debug, Clone, PartialEq, IntoOwned)]
+pub struct GameManager<'a> {
+    root: PlistDict<'a>,
+}
+
+impl<'a> GameManager<'a> {
+    /// Wraps the root dictionary of a game manager save file (see
+    /// [`SaveFile::root`](crate::savefile::SaveFile::root))
+    pub fn new(root: PlistDict<'a>) -> Self {
+        GameManager { root }
+    }
+
+    /// Parses the given decrypted game manager XML
+    pub fn from_xml(xml: &'a str) -> Result<Self, SaveFileError> {
+        Ok(GameManager::new(PlistDict::from_xml(xml)?))
+    }
+
+    /// Writes this game manager back into XML, which can then be encrypted via
+    /// [`encrypt`](crate::savefile::encrypt)
+    pub fn to_xml(&self) -> String {
+        self.root().to_xml()
+    }
+
+    /// The root dictionary of the game manager
+    pub fn root(&self) -> &PlistDict<'a> {
+        &self.root
+    }
+
+    /// Mutable access to the root dictionary of the game manager, e.g. for modifying keys dash-rs
+    /// does not know about
+    pub fn root_mut(&mut self) -> &mut PlistDict<'a> {
+        &mut self.root
+    }
+
+    pub fn into_root(self) -> PlistDict<'a> {
+        self.root
+    }
+
+    /// Retrieves the raw value stored at the given key of the root dictionary
+    pub fn get(&self, key: &str) -> Option<&PlistValue<'a>> {
+        self.root().get(key)
+    }
+
+    /// The name of the player
+    ///
+    /// ## GD Internals:
+    /// This value is stored at key `playerName`
+    pub fn player_name(&self) -> Option<&str> {
+        self.get("playerName").and_then(PlistValue::as_str)
+    }
+
+    /// The player's user ID
+    ///
+    /// ## GD Internals:
+    /// This value is stored at key `playerUserID`
+    pub fn user_id(&self) -> Option<u64> {
+        self.get("playerUserID").and_then(integer)
+    }
+
+    /// The ID of the account the player is logged into, if any
+    ///
+    /// ## GD Internals:
+    /// This value is stored at key `GJA_003`
+    pub fn account_id(&self) -> Option<u64> {
+        self.get("GJA_003").and_then(integer)
+    }
+
+    /// The player's statistics, as shown on the stats page in-game
+    ///
+    /// ## GD Internals:
+    /// The statistics are stored in the dictionary at key `GS_value`
+    pub fn statistics(&self) -> Result<Statistics, DeError<'a>> {
+        match self.get("GS_value") {
+            Some(value) => Statistics::from_plist(value),
+            None => Ok(Statistics::default()),
+        }
+    }
+
+    /// The icons and colors the player has currently selected
+    pub fn icons(&self) -> Result<IconSelection, DeError<'a>> {
+        IconSelection::from_plist_dict(&self.root)
+    }
+
+    /// The IDs of all online levels the player has completed, in the order they are stored in
+    ///
+    /// ## GD Internals:
+    /// Completed levels are stored in the dictionary at key `GS_completed`. Online levels are
+    /// stored at keys of the form `c_<level id>`.
+    pub fn completed_levels(&self) -> Vec<u64> {
+        match self.get("GS_completed").and_then(PlistValue::as_dict) {
+            Some(completed) => completed
+                .iter()
+                .filter_map(|(key, _)| key.strip_prefix(COMPLETED_ONLINE_LEVEL_PREFIX))
+                .filter_map(|id| id.parse().ok())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Constructs a [`CompletionFilter`] excluding all levels the player has completed from a
+    /// search
+    pub fn completion_filter(&self) -> CompletionFilter {
+        CompletionFilter::exclude(self.completed_levels())
+    }
+
+    /// The value of the given game variable, which are mostly used for settings (e.g. `"0026"` for
+    /// "auto-retry"). Returns [`None`] if the variable was never set.
+    ///
+    /// ## GD Internals:
+    /// Game variables are stored in the dictionary at key `valueKeeper`, at keys of the form
+    /// `gv_<variable>`, with values `"0"` or `"1"`.
+    pub fn game_variable(&self, variable: &str) -> Option<bool> {
+        self.get("valueKeeper")
+            .and_then(PlistValue::as_dict)
+            .and_then(|keeper| keeper.get(&format!("gv_{}", variable)))
+            .and_then(integer::<u8>)
+            .map(|value| value != 0)
+    }
+
+    /// Sets the value of the given game variable, see [`GameManager::game_variable`]
+    pub fn set_game_variable(&mut self, variable: &str, value: bool) {
+        let root = self.root_mut();
+
+        if root.get("valueKeeper").and_then(PlistValue::as_dict).is_none() {
+            root.insert("valueKeeper", PlistValue::Dict(PlistDict::new()));
+        }
+
+        if let Some(PlistValue::Dict(keeper)) = root.get_mut("valueKeeper") {
+            keeper.insert(
+                format!("gv_{}", variable),
+                PlistValue::String(Cow::Borrowed(if value { "1" } else { "0" })),
+            );
+        }
+    }
+}
+
+/// Reads an integer the game might have stored as either a string or an integer
+fn integer<T: FromStr + TryFrom<i64>>(value: &PlistValue) -> Option<T> {
+    match value {
+        PlistValue::Integer(integer) => T::try_from(*integer).ok(),
+        PlistValue::String(string) => string.parse().ok(),
+        _ => None,
+    }
+}
+
+/// The player's statistics
+///
+/// ## GD Internals:
+/// The game stores these as strings in a dictionary whose keys are (stringified) integers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Dash)]
+pub struct Statistics {
+    #[dash(index = 1)]
+    #[dash(default)]
+    pub jumps: u32,
+
+    #[dash(index = 2)]
+    #[dash(default)]
+    pub attempts: u32,
+
+    /// The amount of main levels completed
+    #[dash(index = 3)]
+    #[dash(default)]
+    pub official_levels_completed: u32,
+
+    #[dash(index = 4)]
+    #[dash(default)]
+    pub online_levels_completed: u32,
+
+    #[dash(index = 5)]
+    #[dash(default)]
+    pub demons: u32,
+
+    #[dash(index = 6)]
+    #[dash(default)]
+    pub stars: u32,
+
+    #[dash(index = 7)]
+    #[dash(default)]
+    pub map_packs_completed: u32,
+
+    #[dash(index = 8)]
+    #[dash(default)]
+    pub secret_coins: u32,
+
+    /// The amount of players destroyed on the main menu
+    #[dash(index = 9)]
+    #[dash(default)]
+    pub players_destroyed: u32,
+
+    #[dash(index = 10)]
+    #[dash(default)]
+    pub levels_liked: u32,
+
+    #[dash(index = 11)]
+    #[dash(default)]
+    pub levels_rated: u32,
+
+    #[dash(index = 12)]
+    #[dash(default)]
+    pub user_coins: u32,
+
+    #[dash(index = 13)]
+    #[dash(default)]
+    pub diamonds: u32,
+
+    /// The amount of mana orbs the player currently has (not counting spent ones)
+    #[dash(index = 14)]
+    #[dash(default)]
+    pub orbs: u32,
+
+    #[dash(index = 15)]
+    #[dash(default)]
+    pub daily_levels_completed: u32,
+
+    /// The amount of mana orbs the player has collected in total
+    #[dash(index = 22)]
+    #[dash(default)]
+    pub total_orbs: u32,
+
+    #[dash(index = 28)]
+    #[dash(default)]
+    pub moons: u32,
+}
+
+impl<'de> PlistFormat<'de> for Statistics {}
+
+/// The icons and colors the player has selected
+///
+/// Indexing of icons is the same as for [`Profile`](crate::model::user::profile::Profile), meaning
+/// it is 1-based and goes left-to-right and top-to-bottom in the icon selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Dash)]
+pub struct IconSelection {
+    #[dash(index = "playerFrame")]
+    #[dash(default)]
+    pub cube_index: u16,
+
+    #[dash(index = "playerShip")]
+    #[dash(default)]
+    pub ship_index: u8,
+
+    #[dash(index = "playerBall")]
+    #[dash(default)]
+    pub ball_index: u8,
+
+    #[dash(index = "playerBird")]
+    #[dash(default)]
+    pub ufo_index: u8,
+
+    #[dash(index = "playerDart")]
+    #[dash(default)]
+    pub wave_index: u8,
+
+    #[dash(index = "playerRobot")]
+    #[dash(default)]
+    pub robot_index: u8,
+
+    #[dash(index = "playerSpider")]
+    #[dash(default)]
+    pub spider_index: u8,
+
+    #[dash(index = "playerSwing")]
+    #[dash(default)]
+    pub swing_index: u8,
+
+    #[dash(index = "playerJetpack")]
+    #[dash(default)]
+    pub jetpack_index: u8,
+
+    #[dash(index = "playerDeathEffect")]
+    #[dash(default)]
+    pub death_effect_index: u8,
+
+    #[dash(index = "playerColor")]
+    #[dash(default)]
+    pub primary_color: Color,
+
+    #[dash(index = "playerColor2")]
+    #[dash(default)]
+    pub secondary_color: Color,
+
+    #[dash(index = "playerGlow")]
+    #[dash(default)]
+    pub has_glow: bool,
+}
+
+impl<'de> PlistFormat<'de> for IconSelection {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{model::user::Color, savefile::manager::GameManager};
+
+    const XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>valueKeeper</k><d><k>gv_0026</k><s>1</s><k>gv_0052</k><s>0</s></d><k>playerName</k><s>stadust</s><k>playerUserID</k><i>8451</i><k>playerFrame</k><i>98</i><k>playerShip</k><i>12</i><k>playerColor</k><i>3</i><k>playerColor2</k><i>12</i><k>playerGlow</k><t /><k>GS_value</k><d><k>1</k><s>123456</s><k>2</k><s>7890</s><k>6</k><s>321</s><k>22</k><s>4500</s></d><k>GS_completed</k><d><k>c_11774780</k><s>1</s><k>n_1</k><s>1</s><k>c_897837</k><s>1</s></d><k>GJA_003</k><i>71</i></dict></plist>"#;
+
+    #[test]
+    fn typed_accessors() {
+        let manager = GameManager::from_xml(XML).unwrap();
+
+        assert_eq!(manager.player_name(), Some("stadust"));
+        assert_eq!(manager.user_id(), Some(8451));
+        assert_eq!(manager.account_id(), Some(71));
+        assert_eq!(manager.game_variable("0026"), Some(true));
+        assert_eq!(manager.game_variable("0052"), Some(false));
+        assert_eq!(manager.game_variable("0001"), None);
+
+        let statistics = manager.statistics().unwrap();
+
+        assert_eq!(statistics.jumps, 123456);
+        assert_eq!(statistics.attempts, 7890);
+        assert_eq!(statistics.stars, 321);
+        assert_eq!(statistics.total_orbs, 4500);
+        assert_eq!(statistics.demons, 0);
+
+        let icons = manager.icons().unwrap();
+
+        assert_eq!(icons.cube_index, 98);
+        assert_eq!(icons.ship_index, 12);
+        assert_eq!(icons.ball_index, 0);
+        assert_eq!(icons.primary_color, Color::Known(0, 255, 255));
+        assert!(icons.has_glow);
+
+        assert_eq!(manager.completed_levels(), vec![11774780, 897837]);
+    }
+
+    #[test]
+    fn passthrough() {
+        let mut manager = GameManager::from_xml(XML).unwrap();
+
+        assert_eq!(manager.to_xml(), XML);
+
+        manager.set_game_variable("0052", true);
+        manager.set_game_variable("0001", false);
+
+        let xml = manager.to_xml();
+        let manager = GameManager::from_xml(&xml).unwrap();
+
+        assert_eq!(manager.game_variable("0052"), Some(true));
+        assert_eq!(manager.game_variable("0001"), Some(false));
+        assert_eq!(manager.player_name(), Some("stadust"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Module for reading and writing Geometry Dash's local save files, `CCGameManager.dat` and
+//! `CCLocalLevels.dat`
+//!
+//! ## GD Internals:
+//! On Windows (and Linux, where the game runs through wine), both files contain a plist-style XML
+//! document, which is gzip compressed, URL-safe base64 encoded and finally XOR-ed with the key `11`.
+//! The macOS version uses a different (AES based) encryption, which is not supported.
+
+use base64::{
+    alphabet::URL_SAFE,
+    engine::{general_purpose::GeneralPurpose, DecodePaddingMode, GeneralPurposeConfig},
+    Engine,
+};
+use flate2::{
+    read::{GzDecoder, GzEncoder},
+    Compression,
+};
+use std::{io::Read, string::FromUtf8Error};
+use thiserror::Error;
+
+pub use crate::serde::{PlistDict, PlistError, PlistValue};
+
+pub mod gmd;
+pub mod manager;
+
+/// The key every byte of a save file is XOR-ed with
+const XOR_KEY: u8 = 11;
+
+/// URL-safe base64 engine that does not care about padding. The game pads the base64 it writes,
+/// but does not require padding when reading save files.
+const SAVEFILE_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug, Error)]
+pub enum SaveFileError {
+    #[error("{0}")]
+    Base64(#[from] base64::DecodeError),
+
+    /// Error during (de)compression
+    #[error("{0}")]
+    Compression(#[from] std::io::Error),
+
+    /// The decompressed save file was not valid UTF-8
+    #[error("{0}")]
+    Utf8(#[from] FromUtf8Error),
+
+    /// The XML inside the save file does not follow the plist format the game uses
+    #[error("{0}")]
+    MalformedXml(#[from] PlistError),
+}
+
+/// Decrypts the contents of a save file, returning the plist XML stored inside
+pub fn decrypt(data: &[u8]) -> Result<String, SaveFileError> {
+    let xored = data.iter().map(|byte| byte ^ XOR_KEY).collect::<Vec<_>>();
+
+    // Save files written by the game can be followed by some null bytes (after XOR-ing)
+    let end = xored.iter().rposition(|&byte| byte != 0).map_or(0, |idx| idx + 1);
+    let decoded = SAVEFILE_BASE64.decode(&xored[..end])?;
+
+    let mut decompressed = Vec::new();
+
+    GzDecoder::new(&decoded[..]).read_to_end(&mut decompressed)?;
+
+    Ok(String::from_utf8(decompressed)?)
+}
+
+/// Encrypts the given plist XML into the format save files are stored in, the inverse of
+/// [`decrypt`]
+pub fn encrypt(xml: &str) -> Result<Vec<u8>, SaveFileError> {
+    let mut compressed = Vec::new();
+
+    GzEncoder::new(xml.as_bytes(), Compression::default()).read_to_end(&mut compressed)?;
+
+    Ok(SAVEFILE_BASE64.encode(compressed).bytes().map(|byte| byte ^ XOR_KEY).collect())
+}
+
+/// A decrypted save file
+///
+/// The plist XML is decrypted eagerly, but only parsed when [`SaveFile::root`] is called. The
+/// resulting [`PlistDict`] borrows from the XML held inside this struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveFile {
+    xml: String,
+}
+
+impl SaveFile {
+    /// Decrypts the given save file contents
+    pub fn from_encrypted(data: &[u8]) -> Result<SaveFile, SaveFileError> {
+        decrypt(data).map(|xml| SaveFile { xml })
+    }
+
+    /// Wraps some already decrypted plist XML
+    pub fn from_xml(xml: String) -> SaveFile {
+        SaveFile { xml }
+    }
+
+    /// The decrypted plist XML
+    pub fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    /// Parses the root dictionary of this save file
+    pub fn root(&self) -> Result<PlistDict<'_>, SaveFileError> {
+        Ok(PlistDict::from_xml(&self.xml)?)
+    }
+
+    /// Encrypts this save file into the format the game stores it in
+    pub fn to_encrypted(&self) -> Result<Vec<u8>, SaveFileError> {
+        encrypt(&self.xml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::savefile::{decrypt, encrypt, PlistValue, SaveFile};
+
+    const XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>valueKeeper</k><d><k>gv_0001</k><s>1</s></d><k>playerName</k><s>stadust</s></dict></plist>"#;
+
+    #[test]
+    fn encryption_round_trip() {
+        let encrypted = encrypt(XML).unwrap();
+
+        // XOR-ed base64 of gzip magic bytes
+        assert!(encrypted.starts_with(b"C?xB"));
+        assert_eq!(decrypt(&encrypted).unwrap(), XML);
+
+        // trailing null bytes (after XOR) and missing padding are tolerated
+        let mut padded = encrypted.iter().copied().filter(|&byte| byte != b'=' ^ 11).collect::<Vec<_>>();
+        padded.extend_from_slice(&[11, 11]);
+
+        assert_eq!(decrypt(&padded).unwrap(), XML);
+    }
+
+    #[test]
+    fn save_file_view() {
+        let save = SaveFile::from_encrypted(&encrypt(XML).unwrap()).unwrap();
+        let root = save.root().unwrap();
+
+        assert_eq!(root.get("playerName").and_then(PlistValue::as_str), Some("stadust"));
+        assert_eq!(
+            root.get("valueKeeper")
+                .and_then(PlistValue::as_dict)
+                .and_then(|keeper| keeper.get("gv_0001"))
+                .and_then(PlistValue::as_str),
+            Some("1")
+        );
+        assert_eq!(SaveFile::from_encrypted(&save.to_encrypted().unwrap()).unwrap(), save);
+    }
+}
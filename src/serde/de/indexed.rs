@@ -91,7 +91,12 @@ impl<'de> IndexedDeserializer<'de> {
     }
 
     fn is_next_empty(&self) -> bool {
-        &self.input[self.position() + self.delimiter.len()..self.position() + 2 * self.delimiter.len()] == self.delimiter
+        // If the input ends with the current token (or with the delimiter following it), the next token
+        // is the empty string as well
+        match self.input.get(self.position() + self.delimiter.len()..) {
+            Some(rest) => rest.is_empty() || rest.starts_with(self.delimiter),
+            None => true,
+        }
     }
 
     fn is_eof(&self) -> bool {
@@ -332,17 +337,21 @@ impl<'a, 'de> Deserializer<'de> for &'a mut IndexedDeserializer<'de> {
     {
         // We are still very much not self describing, however we do need to correctly handle unimplemented
         // indices. By the time this is called, they key itself will already have been popped in our
-        // `MapAccess` implementation. This means we need to skip exactly one item! We'll feed a `None` to
-        // the visitor. Because idk what we really wanna do here otherwise
-        let _token = self.consume_token();
+        // `MapAccess` implementation. This means we need to skip exactly one item! We'll feed that item to
+        // the visitor as a string (so that it can be preserved as an unknown field), or `None` if the input
+        // ended prematurely.
+        let token = self.consume_token();
 
         debug!(
             "Ignored token {:?}. Preceding token (potentially an unmapped index) was {:?}",
-            _token,
+            token,
             self.nth_last(1)
         );
 
-        visitor.visit_none()
+        match token {
+            Some(token) => visitor.visit_borrowed_str(token),
+            None => visitor.visit_none(),
+        }
     }
 }
 
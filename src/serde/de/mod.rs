@@ -4,3 +4,4 @@
 
 pub mod error;
 pub mod indexed;
+pub mod plist;
@@ -0,0 +1,348 @@
+//! Module containing the deserializer for RobTop's plist dialect
+
+use super::error::Error;
+use crate::serde::{plist::is_element, PlistDict, PlistValue, ARRAY_MARKER};
+use serde::{
+    de,
+    de::{DeserializeSeed, IntoDeserializer, Visitor},
+    forward_to_deserialize_any, Deserializer,
+};
+use std::{borrow::Cow, slice::Iter, str::FromStr};
+
+/// Deserializer for RobTop's plist dialect
+///
+/// Operates on the [`PlistValue`]s of an already parsed plist. Strings are borrowed from the XML
+/// the plist was parsed from wherever possible.
+///
+/// Since the game is very inconsistent in whether it stores numbers and booleans as `<s>`, `<i>` or
+/// `<t />` (and even stores some of them differently depending on their value), this
+/// deserializer converts freely between those representations based on what is requested.
+#[derive(Debug)]
+pub struct PlistDeserializer<'a, 'de> {
+    value: &'a PlistValue<'de>,
+}
+
+impl<'a, 'de> PlistDeserializer<'a, 'de> {
+    pub fn new(value: &'a PlistValue<'de>) -> Self {
+        PlistDeserializer { value }
+    }
+
+    fn invalid_type<T>(&self, expected: &'static str) -> Result<T, Error<'de>> {
+        Err(Error::Custom {
+            message: format!("expected {}, found {:?}", expected, self.value),
+            index: None,
+            value: match self.value {
+                PlistValue::String(Cow::Borrowed(value)) => Some(value),
+                _ => None,
+            },
+        })
+    }
+
+    /// Parses a number stored as either a string, integer or real
+    fn parse<T: FromStr>(&self) -> Result<Option<T>, Error<'de>> {
+        let string = match self.value {
+            PlistValue::String(string) => string.trim().to_owned(),
+            PlistValue::Integer(integer) => integer.to_string(),
+            PlistValue::Real(real) => real.to_string(),
+            // The game omits booleans set to false, so there is no way to tell "<t />" and "1" apart
+            PlistValue::Bool(boolean) => (*boolean as u8).to_string(),
+            PlistValue::Dict(_) => return Ok(None),
+        };
+
+        Ok(string.parse().ok())
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method: ident => $visit: ident),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                match self.parse()? {
+                    Some(number) => visitor.$visit(number),
+                    None => self.invalid_type("number"),
+                }
+            }
+        )*
+    };
+}
+
+impl<'a, 'de> Deserializer<'de> for PlistDeserializer<'a, 'de> {
+    type Error = Error<'de>;
+
+    forward_to_deserialize_any! {
+        unit_struct tuple tuple_struct identifier
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8, deserialize_i16 => visit_i16, deserialize_i32 => visit_i32, deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8, deserialize_u16 => visit_u16, deserialize_u32 => visit_u32, deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32, deserialize_f64 => visit_f64
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            PlistValue::String(Cow::Borrowed(string)) => visitor.visit_borrowed_str(string),
+            PlistValue::String(Cow::Owned(string)) => visitor.visit_str(string),
+            PlistValue::Integer(integer) => visitor.visit_i64(*integer),
+            PlistValue::Real(real) => visitor.visit_f64(*real),
+            PlistValue::Bool(boolean) => visitor.visit_bool(*boolean),
+            PlistValue::Dict(dict) if is_array(dict) => self.deserialize_seq(visitor),
+            PlistValue::Dict(_) => self.deserialize_map(visitor),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            PlistValue::Bool(boolean) => visitor.visit_bool(*boolean),
+            _ => match self.parse::<i64>()? {
+                Some(integer) => visitor.visit_bool(integer != 0),
+                None => self.invalid_type("boolean"),
+            },
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            PlistValue::String(Cow::Borrowed(string)) => visitor.visit_borrowed_str(string),
+            PlistValue::String(Cow::Owned(string)) => visitor.visit_str(string),
+            PlistValue::Integer(integer) => visitor.visit_string(integer.to_string()),
+            PlistValue::Real(real) => visitor.visit_string(real.to_string()),
+            _ => self.invalid_type("string"),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(Error::Unsupported("deserialize_bytes"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(Error::Unsupported("deserialize_byte_buf"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // Absent values are simply missing from their dictionary
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            PlistValue::Dict(dict) => visitor.visit_seq(PlistSeqAccess { entries: dict.0.iter() }),
+            _ => self.invalid_type("array"),
+        }
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            PlistValue::Dict(dict) => visitor.visit_map(PlistMapAccess {
+                entries: dict.0.iter(),
+                value: None,
+            }),
+            _ => self.invalid_type("dictionary"),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self, _name: &'static str, _fields: &'static [&'static str], visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self, _name: &'static str, _variants: &'static [&'static str], visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // Only unit variants are supported
+        match self.value {
+            PlistValue::String(string) => visitor.visit_enum(string.as_ref().into_deserializer()),
+            _ => self.invalid_type("string"),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // Hand the value to the visitor, so that it can be preserved as an unknown field. Anything but
+        // plain strings is passed as its XML element, so that the type of the value survives a round
+        // trip (see `PlistSerializer::serialize_newtype_struct`).
+        match self.value {
+            PlistValue::String(Cow::Borrowed(string)) if !is_element(string) => visitor.visit_borrowed_str(string),
+            PlistValue::String(Cow::Owned(string)) if !is_element(string) => visitor.visit_str(string),
+            value => visitor.visit_string(value.to_xml()),
+        }
+    }
+}
+
+/// Deserializer for a plist dictionary that is not wrapped in a [`PlistValue`], such as the root
+/// dictionary of a plist document
+pub(crate) struct PlistDictDeserializer<'a, 'de> {
+    dict: &'a PlistDict<'de>,
+}
+
+impl<'a, 'de> PlistDictDeserializer<'a, 'de> {
+    pub(crate) fn new(dict: &'a PlistDict<'de>) -> Self {
+        PlistDictDeserializer { dict }
+    }
+}
+
+impl<'a, 'de> Deserializer<'de> for PlistDictDeserializer<'a, 'de> {
+    type Error = Error<'de>;
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf option unit unit_struct newtype_struct
+        seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(PlistMapAccess {
+            entries: self.dict.0.iter(),
+            value: None,
+        })
+    }
+}
+
+fn is_array(dict: &PlistDict) -> bool {
+    matches!(dict.get(ARRAY_MARKER), Some(PlistValue::Bool(true)))
+}
+
+struct PlistSeqAccess<'a, 'de> {
+    entries: Iter<'a, (Cow<'de, str>, PlistValue<'de>)>,
+}
+
+impl<'a, 'de> de::SeqAccess<'de> for PlistSeqAccess<'a, 'de> {
+    type Error = Error<'de>;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        match self.entries.find(|(key, _)| key != ARRAY_MARKER) {
+            Some((_, value)) => seed.deserialize(PlistDeserializer::new(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+struct PlistMapAccess<'a, 'de> {
+    entries: Iter<'a, (Cow<'de, str>, PlistValue<'de>)>,
+    value: Option<&'a PlistValue<'de>>,
+}
+
+impl<'a, 'de> de::MapAccess<'de> for PlistMapAccess<'a, 'de> {
+    type Error = Error<'de>;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let (key, value) = match self.entries.next() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        self.value = Some(value);
+
+        let key = match key {
+            Cow::Borrowed(key) => seed.deserialize(de::value::BorrowedStrDeserializer::new(key)),
+            Cow::Owned(key) => seed.deserialize(key.as_str().into_deserializer()),
+        };
+
+        key.map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(PlistDeserializer::new(value)),
+            None => Err(Error::Eof),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serde::{PlistDeserializer, PlistDict, PlistValue},
+        PlistFormat, UnknownFields,
+    };
+    use dash_rs_derive::Dash;
+    use serde::{Deserialize, Serialize};
+    use std::{borrow::Cow, collections::HashMap};
+
+    const XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>k2</k><s>Bloodbath</s><k>k18</k><s>5</s><k>k87</k><r>0.5</r><k>k13</k><t /><k>k14</k><s>0</s><k>tags</k><d><k>_isArr</k><t /><k>k_0</k><s>extreme</s><k>k_1</k><i>2</i></d><k>k99</k><s>ignored</s></dict></plist>"#;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Level<'a> {
+        k2: &'a str,
+        k18: u32,
+        #[serde(default)]
+        k71: Option<u32>,
+        k87: f32,
+        k13: bool,
+        k14: bool,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn deserialize_struct() {
+        let root = PlistValue::Dict(PlistDict::from_xml(XML).unwrap());
+        let level = Level::deserialize(PlistDeserializer::new(&root)).unwrap();
+
+        assert_eq!(
+            level,
+            Level {
+                k2: "Bloodbath",
+                k18: 5,
+                k71: None,
+                k87: 0.5,
+                k13: true,
+                k14: false,
+                tags: vec!["extreme".to_owned(), "2".to_owned()]
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_to_hashmap() {
+        let xml = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>gv_0001</k><s>1</s><k>gv_0002</k><i>0</i><k>gv_0010</k><t /></dict></plist>"#;
+        let root = PlistValue::Dict(PlistDict::from_xml(xml).unwrap());
+        let map = HashMap::<&str, u8>::deserialize(PlistDeserializer::new(&root)).unwrap();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["gv_0001"], 1);
+        assert_eq!(map["gv_0002"], 0);
+        assert_eq!(map["gv_0010"], 1);
+    }
+
+    #[derive(Debug, Dash)]
+    struct Song<'a> {
+        #[dash(index = "k2")]
+        name: Cow<'a, str>,
+
+        #[dash(unknown_fields)]
+        unknown_fields: UnknownFields<'a>,
+    }
+
+    impl<'de> PlistFormat<'de> for Song<'de> {}
+
+    #[test]
+    fn unknown_fields_round_trip() {
+        let xml = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>k2</k><s>Stereo Madness</s><k>kCEK</k><i>4</i><k>k13</k><t /><k>k50</k><r>0.5</r><k>k99</k><s>plain</s><k>k98</k><s>&lt;i&gt;1&lt;/i&gt;</s><k>kI6</k><d><k>0</k><s>1</s><k>1</k><d /></d></dict></plist>"#;
+        let song = Song::from_plist_str(xml).unwrap();
+
+        assert_eq!(song.name, "Stereo Madness");
+        assert_eq!(song.unknown_fields.len(), 6);
+        assert_eq!(song.unknown_fields.get("kCEK"), Some("<i>4</i>"));
+        assert_eq!(song.unknown_fields.get("k13"), Some("<t />"));
+        assert_eq!(song.unknown_fields.get("k99"), Some("plain"));
+        assert_eq!(song.unknown_fields.get("k98"), Some("<s>&lt;i&gt;1&lt;/i&gt;</s>"));
+        assert_eq!(song.unknown_fields.get("kI6"), Some("<d><k>0</k><s>1</s><k>1</k><d /></d>"));
+
+        assert_eq!(song.to_plist_string().unwrap(), xml);
+    }
+}
@@ -1,11 +1,18 @@
 mod de;
+mod plist;
 mod ser;
 mod thunk;
+mod unknown;
 
-pub use de::{error::Error as DeError, indexed::IndexedDeserializer};
-pub use ser::{error::Error as SerError, indexed::IndexedSerializer, request::RequestSerializer};
+use de::plist::PlistDictDeserializer;
+pub use de::{error::Error as DeError, indexed::IndexedDeserializer, plist::PlistDeserializer};
+pub(crate) use plist::ARRAY_MARKER;
+pub use plist::{PlistDict, PlistError, PlistValue};
+pub use ser::{error::Error as SerError, indexed::IndexedSerializer, plist::PlistSerializer, request::RequestSerializer};
 use serde::{Deserializer, Serializer};
-pub use thunk::{Base64Decoder, PercentDecoder, ProcessError, Thunk, ThunkProcessor};
+pub use thunk::{Base64Decoder, IdList, PercentDecoder, ProcessError, Thunk, ThunkProcessor};
+pub(crate) use unknown::{UnknownFieldsCollector, UNKNOWN_VALUE};
+pub use unknown::UnknownFields;
 
 use std::{borrow::Cow, io::Write};
 
@@ -45,6 +52,40 @@ pub trait GJFormat<'de>: Dash<'de> {
     }
 }
 
+/// Trait for objects that can be (de)serialized from RobTop's plist dialect, which is used in save
+/// files and `.gmd` exports (see [`crate::savefile`]).
+///
+/// This is the plist equivalent of [`GJFormat`].
+pub trait PlistFormat<'de>: Dash<'de> {
+    fn from_plist(value: &PlistValue<'de>) -> Result<Self, de::error::Error<'de>> {
+        Self::dash_deserialize(PlistDeserializer::new(value))
+    }
+
+    fn from_plist_dict(dict: &PlistDict<'de>) -> Result<Self, de::error::Error<'de>> {
+        Self::dash_deserialize(PlistDictDeserializer::new(dict))
+    }
+
+    /// Deserializes the root dictionary of the given plist document
+    fn from_plist_str(xml: &'de str) -> Result<Self, de::error::Error<'de>> {
+        let root = PlistDict::from_xml(xml).map_err(|err| serde::de::Error::custom(err.to_string()))?;
+
+        Self::from_plist_dict(&root)
+    }
+
+    fn to_plist(&self) -> Result<PlistValue<'static>, ser::error::Error> {
+        self.dash_serialize(PlistSerializer::new())
+            .map(|value| value.unwrap_or_else(|| PlistValue::Dict(PlistDict::new())))
+    }
+
+    /// Serializes this object as the root dictionary of a plist document
+    fn to_plist_string(&self) -> Result<String, ser::error::Error> {
+        match self.to_plist()? {
+            PlistValue::Dict(root) => Ok(root.to_xml()),
+            _ => Err(ser::error::Error::Custom("the root of a plist must be a dictionary".to_owned())),
+        }
+    }
+}
+
 /// Trait describing an intermediate step between the raw Geomtry Dash data format, and the APIs
 /// exposed by dash-rs
 ///
@@ -111,6 +152,59 @@ impl<'b, T: ThunkProcessor> InternalProxy for Thunk<'b, T> {
     }
 }
 
+/// Trait for objects that can be detached from the input they were deserialized from
+///
+/// Most dash-rs types borrow from the data they were parsed from. This trait converts them into
+/// types with `'static` lifetime, by cloning all borrowed data, so that they can be stored
+/// independently of the (e.g. HTTP response) buffer they were parsed from. For models, this trait is
+/// implemented via `#[derive(IntoOwned)]`.
+pub trait IntoOwned {
+    type Owned;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+crate::into_owned_identity!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+crate::into_owned_identity!(f32, f64, char, bool, String, ());
+
+impl<'b, T: ToOwned + ?Sized + 'static> IntoOwned for Cow<'b, T> {
+    type Owned = Cow<'static, T>;
+
+    fn into_owned(self) -> Cow<'static, T> {
+        Cow::Owned(Cow::into_owned(self))
+    }
+}
+
+impl<'b, T: ThunkProcessor> IntoOwned for Thunk<'b, T>
+where
+    T::Output<'b>: IntoOwned<Owned = T::Output<'static>>,
+{
+    type Owned = Thunk<'static, T>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            Thunk::Unprocessed(unprocessed) => Thunk::Unprocessed(IntoOwned::into_owned(unprocessed)),
+            Thunk::Processed(processed) => Thunk::Processed(processed.into_owned()),
+        }
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
 impl<T: InternalProxy> InternalProxy for Option<T> {
     type DeserializeProxy = Option<T::DeserializeProxy>;
     type SerializeProxy<'a> = Option<T::SerializeProxy<'a>> where Self: 'a;
@@ -0,0 +1,489 @@
+//! Module containing a zero-copy view of RobTop's plist dialect, which is used for the XML inside
+//! save files and `.gmd` exports
+//!
+//! ## GD Internals:
+//! The game uses abbreviated tag names for its plists: `<k>` for keys, `<s>` for strings, `<i>` for
+//! integers, `<r>` for reals, `<t />` for `true` and `<d>` for (nested) dictionaries. Only the root
+//! dictionary uses the full `<dict>` tag. The full Apple plist tag names are accepted as well.
+
+use crate::IntoOwned;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt::Write};
+use thiserror::Error;
+
+/// Key marking a dictionary as an array
+///
+/// ## GD Internals:
+/// The game has no separate array type in its plists. Instead, arrays are dictionaries with the
+/// key `_isArr` set to `true`, whose elements are stored at keys `k_0`, `k_1`, ...
+pub(crate) const ARRAY_MARKER: &str = "_isArr";
+
+/// The XML given to [`PlistDict::from_xml`] does not follow the plist format the game uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("Malformed plist XML at byte {position}: {message}")]
+pub struct PlistError {
+    /// The byte offset into the XML at which the error was detected
+    pub position: usize,
+
+    /// Description of what was wrong
+    pub message: &'static str,
+}
+
+/// Whether the given string looks like an XML element, as opposed to plain text. Values stored in
+/// [`UnknownFields`](crate::serde::UnknownFields) use this to tell apart strings from values
+/// of other types.
+pub(crate) fn is_element(value: &str) -> bool {
+    value.starts_with('<')
+}
+
+/// A single value inside a plist dictionary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlistValue<'a> {
+    #[serde(borrow)]
+    String(Cow<'a, str>),
+    Integer(i64),
+    Real(f64),
+    Bool(bool),
+    #[serde(borrow)]
+    Dict(PlistDict<'a>),
+}
+
+impl<'a> PlistValue<'a> {
+    /// The string stored in this value, if it is a string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PlistValue::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// The integer stored in this value, if it is an integer
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            PlistValue::Integer(integer) => Some(*integer),
+            _ => None,
+        }
+    }
+
+    /// The real number stored in this value, if it is a real or an integer
+    pub fn as_real(&self) -> Option<f64> {
+        match self {
+            PlistValue::Real(real) => Some(*real),
+            PlistValue::Integer(integer) => Some(*integer as f64),
+            _ => None,
+        }
+    }
+
+    /// The boolean stored in this value, if it is a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PlistValue::Bool(boolean) => Some(*boolean),
+            _ => None,
+        }
+    }
+
+    /// The dictionary stored in this value, if it is a dictionary
+    pub fn as_dict(&self) -> Option<&PlistDict<'a>> {
+        match self {
+            PlistValue::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    /// Parses a single value element, such as `<i>5</i>` or `<d><k>a</k><s>b</s></d>`
+    pub fn from_xml(xml: &'a str) -> Result<PlistValue<'a>, PlistError> {
+        let mut reader = XmlReader { input: xml, position: 0 };
+        let value = reader.read_value()?;
+
+        if !reader.input[reader.position..].trim().is_empty() {
+            return Err(reader.error("trailing characters"));
+        }
+
+        Ok(value)
+    }
+
+    /// Writes this value as a single value element, the inverse of [`PlistValue::from_xml`]
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::new();
+
+        write_value(self, &mut xml);
+
+        xml
+    }
+}
+
+impl<'a> IntoOwned for PlistValue<'a> {
+    type Owned = PlistValue<'static>;
+
+    fn into_owned(self) -> PlistValue<'static> {
+        match self {
+            PlistValue::String(string) => PlistValue::String(Cow::Owned(string.into_owned())),
+            PlistValue::Integer(integer) => PlistValue::Integer(integer),
+            PlistValue::Real(real) => PlistValue::Real(real),
+            PlistValue::Bool(boolean) => PlistValue::Bool(boolean),
+            PlistValue::Dict(dict) => PlistValue::Dict(dict.into_owned()),
+        }
+    }
+}
+
+/// A plist dictionary, borrowing its keys and strings from the XML it was parsed from wherever
+/// possible
+///
+/// Entries are kept in the order they appear in the XML.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct PlistDict<'a>(#[serde(borrow)] pub Vec<(Cow<'a, str>, PlistValue<'a>)>);
+
+impl<'a> PlistDict<'a> {
+    pub fn new() -> Self {
+        PlistDict(Vec::new())
+    }
+
+    /// Parses a full plist document (`<?xml ...?><plist><dict>...</dict></plist>`), returning its
+    /// root dictionary
+    pub fn from_xml(xml: &'a str) -> Result<PlistDict<'a>, PlistError> {
+        let mut reader = XmlReader { input: xml, position: 0 };
+
+        reader.expect_open("plist")?;
+
+        let root = match reader.next_tag()? {
+            Tag::Open(name) if is_tag(name, "d") => reader.read_dict(name)?,
+            Tag::SelfClosing(name) if is_tag(name, "d") => PlistDict::new(),
+            _ => return Err(reader.error("expected root dictionary")),
+        };
+
+        reader.expect_close("plist")?;
+
+        Ok(root)
+    }
+
+    /// Writes this dictionary as the root of a full plist document, in the format the game uses
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict>"#);
+
+        self.write_entries(&mut xml);
+
+        xml.push_str("</dict></plist>");
+        xml
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Retrieves the value stored at the given key, if present
+    pub fn get(&self, key: &str) -> Option<&PlistValue<'a>> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, value)| value)
+    }
+
+    /// Retrieves a mutable reference to the value stored at the given key, if present
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut PlistValue<'a>> {
+        self.0.iter_mut().find(|(k, _)| k == key).map(|(_, value)| value)
+    }
+
+    /// Sets the value stored at the given key, appending a new entry if the key is not yet present
+    pub fn insert(&mut self, key: impl Into<Cow<'a, str>>, value: PlistValue<'a>) {
+        let key = key.into();
+
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    /// Removes the value stored at the given key, returning it if it was present
+    pub fn remove(&mut self, key: &str) -> Option<PlistValue<'a>> {
+        let idx = self.0.iter().position(|(k, _)| k == key)?;
+
+        Some(self.0.remove(idx).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PlistValue<'a>)> {
+        self.0.iter().map(|(key, value)| (key.as_ref(), value))
+    }
+
+    fn write_entries(&self, xml: &mut String) {
+        // The game omits booleans set to false instead of writing them as `<f />`
+        for (key, value) in self.0.iter().filter(|(_, value)| *value != PlistValue::Bool(false)) {
+            xml.push_str("<k>");
+            escape_into(key, xml);
+            xml.push_str("</k>");
+
+            write_value(value, xml);
+        }
+    }
+}
+
+fn write_value(value: &PlistValue, xml: &mut String) {
+    // Writing to a String cannot fail
+    match value {
+        PlistValue::String(string) => {
+            xml.push_str("<s>");
+            escape_into(string, xml);
+            xml.push_str("</s>");
+        },
+        PlistValue::Integer(integer) => {
+            let _ = write!(xml, "<i>{}</i>", integer);
+        },
+        PlistValue::Real(real) => {
+            let _ = write!(xml, "<r>{}</r>", real);
+        },
+        PlistValue::Bool(true) => xml.push_str("<t />"),
+        PlistValue::Bool(false) => xml.push_str("<f />"),
+        PlistValue::Dict(dict) if dict.is_empty() => xml.push_str("<d />"),
+        PlistValue::Dict(dict) => {
+            xml.push_str("<d>");
+            dict.write_entries(xml);
+            xml.push_str("</d>");
+        },
+    }
+}
+
+impl<'a> IntoOwned for PlistDict<'a> {
+    type Owned = PlistDict<'static>;
+
+    fn into_owned(self) -> PlistDict<'static> {
+        PlistDict(
+            self.0
+                .into_iter()
+                .map(|(key, value)| (Cow::Owned(key.into_owned()), value.into_owned()))
+                .collect(),
+        )
+    }
+}
+
+/// The tag names the game uses, together with their full plist equivalent
+const TAG_NAMES: [(&str, &str); 7] = [
+    ("k", "key"),
+    ("s", "string"),
+    ("i", "integer"),
+    ("r", "real"),
+    ("t", "true"),
+    ("f", "false"),
+    ("d", "dict"),
+];
+
+/// Checks whether the given tag name is either the abbreviated tag name used by the game, or its
+/// full plist equivalent
+fn is_tag(name: &str, abbreviation: &str) -> bool {
+    name == abbreviation || TAG_NAMES.iter().any(|&(short, long)| short == abbreviation && long == name)
+}
+
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    SelfClosing(&'a str),
+}
+
+struct XmlReader<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    fn error(&self, message: &'static str) -> PlistError {
+        PlistError {
+            position: self.position,
+            message,
+        }
+    }
+
+    /// Reads the next tag, skipping any whitespace and `<?...?>` declarations before it
+    fn next_tag(&mut self) -> Result<Tag<'a>, PlistError> {
+        loop {
+            let rest = self.input[self.position..].trim_start();
+            self.position = self.input.len() - rest.len();
+
+            if !rest.starts_with('<') {
+                return Err(self.error("expected tag"));
+            }
+
+            let end = rest.find('>').ok_or_else(|| self.error("unterminated tag"))?;
+            let content = &rest[1..end];
+
+            self.position += end + 1;
+
+            if content.starts_with('?') {
+                continue;
+            }
+
+            // Attributes (such as the version of the plist tag) are ignored
+            let name = |content: &'a str| content.split_whitespace().next().unwrap_or_default();
+
+            return Ok(if let Some(content) = content.strip_prefix('/') {
+                Tag::Close(name(content))
+            } else if let Some(content) = content.strip_suffix('/') {
+                Tag::SelfClosing(name(content))
+            } else {
+                Tag::Open(name(content))
+            });
+        }
+    }
+
+    fn expect_open(&mut self, expected: &str) -> Result<(), PlistError> {
+        match self.next_tag()? {
+            Tag::Open(name) if name == expected => Ok(()),
+            _ => Err(self.error("unexpected tag")),
+        }
+    }
+
+    fn expect_close(&mut self, expected: &str) -> Result<(), PlistError> {
+        match self.next_tag()? {
+            Tag::Close(name) if name == expected => Ok(()),
+            _ => Err(self.error("unexpected tag")),
+        }
+    }
+
+    /// Reads the text content of the element whose opening tag was just read, including its
+    /// closing tag
+    fn read_text(&mut self, name: &str) -> Result<Cow<'a, str>, PlistError> {
+        let rest = &self.input[self.position..];
+        let end = rest.find('<').ok_or_else(|| self.error("unterminated element"))?;
+        let text = unescape(&rest[..end]).ok_or_else(|| self.error("invalid entity"))?;
+
+        self.position += end;
+        self.expect_close(name)?;
+
+        Ok(text)
+    }
+
+    /// Reads the entries of the dictionary whose opening tag was just read, including its closing
+    /// tag
+    fn read_dict(&mut self, name: &str) -> Result<PlistDict<'a>, PlistError> {
+        let mut dict = PlistDict::new();
+
+        loop {
+            let key = match self.next_tag()? {
+                Tag::Close(closing) if closing == name => return Ok(dict),
+                Tag::Open(key) if is_tag(key, "k") => self.read_text(key)?,
+                _ => return Err(self.error("expected key")),
+            };
+
+            let value = self.read_value()?;
+
+            dict.0.push((key, value));
+        }
+    }
+
+    /// Reads a single value element
+    fn read_value(&mut self) -> Result<PlistValue<'a>, PlistError> {
+        Ok(match self.next_tag()? {
+            Tag::Open(tag) if is_tag(tag, "s") => PlistValue::String(self.read_text(tag)?),
+            Tag::SelfClosing(tag) if is_tag(tag, "s") => PlistValue::String(Cow::Borrowed("")),
+            Tag::Open(tag) if is_tag(tag, "i") => {
+                PlistValue::Integer(self.read_text(tag)?.trim().parse().map_err(|_| self.error("invalid integer"))?)
+            },
+            Tag::Open(tag) if is_tag(tag, "r") => {
+                PlistValue::Real(self.read_text(tag)?.trim().parse().map_err(|_| self.error("invalid real"))?)
+            },
+            Tag::SelfClosing(tag) if is_tag(tag, "t") => PlistValue::Bool(true),
+            Tag::SelfClosing(tag) if is_tag(tag, "f") => PlistValue::Bool(false),
+            Tag::Open(tag) if is_tag(tag, "d") => PlistValue::Dict(self.read_dict(tag)?),
+            Tag::SelfClosing(tag) if is_tag(tag, "d") => PlistValue::Dict(PlistDict::new()),
+            _ => return Err(self.error("expected value")),
+        })
+    }
+}
+
+/// Resolves the XML entities in the given text, only allocating if there are any
+fn unescape(text: &str) -> Option<Cow<'_, str>> {
+    if !text.contains('&') {
+        return Some(Cow::Borrowed(text));
+    }
+
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(';')?;
+        let entity = &rest[1..end];
+
+        let resolved = match entity {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => match entity.strip_prefix("#x") {
+                Some(hex) => char::from_u32(u32::from_str_radix(hex, 16).ok()?)?,
+                None => char::from_u32(entity.strip_prefix('#')?.parse().ok()?)?,
+            },
+        };
+
+        unescaped.push(resolved);
+        rest = &rest[end + 1..];
+    }
+
+    unescaped.push_str(rest);
+
+    Some(Cow::Owned(unescaped))
+}
+
+fn escape_into(text: &str, xml: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            c => xml.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::plist::{PlistDict, PlistValue};
+    use std::borrow::Cow;
+
+    const XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>LLM_01</k><d><k>_isArr</k><t /><k>k_0</k><d><k>kCEK</k><i>4</i><k>k2</k><s>Tom &amp; Jerry</s><k>k3</k><s /><k>k87</k><r>0.5</r></d></d><k>LLM_02</k><i>37</i><k>LLM_03</k><d /></dict></plist>"#;
+
+    #[test]
+    fn parse_plist() {
+        let root = PlistDict::from_xml(XML).unwrap();
+
+        assert_eq!(root.len(), 3);
+        assert_eq!(root.get("LLM_02"), Some(&PlistValue::Integer(37)));
+        assert_eq!(root.get("LLM_03"), Some(&PlistValue::Dict(PlistDict::new())));
+
+        let levels = root.get("LLM_01").and_then(PlistValue::as_dict).unwrap();
+
+        assert_eq!(levels.get("_isArr"), Some(&PlistValue::Bool(true)));
+
+        let level = levels.get("k_0").and_then(PlistValue::as_dict).unwrap();
+
+        assert_eq!(level.get("kCEK").and_then(PlistValue::as_integer), Some(4));
+        assert_eq!(level.get("k2").and_then(PlistValue::as_str), Some("Tom & Jerry"));
+        assert_eq!(level.get("k3").and_then(PlistValue::as_str), Some(""));
+        assert_eq!(level.get("k87").and_then(PlistValue::as_real), Some(0.5));
+        assert!(matches!(level.get("k2"), Some(PlistValue::String(Cow::Owned(_)))));
+        assert!(matches!(level.get("k3"), Some(PlistValue::String(Cow::Borrowed(_)))));
+    }
+
+    #[test]
+    fn plist_round_trip() {
+        let root = PlistDict::from_xml(XML).unwrap();
+
+        assert_eq!(root.to_xml().replace("<s></s>", "<s />"), XML);
+        assert_eq!(PlistDict::from_xml(&root.to_xml()).unwrap(), root);
+
+        let root = PlistDict::from_xml("<plist><dict><k>a</k><f /><k>b</k><t /></dict></plist>").unwrap();
+
+        assert_eq!(root.get("a"), Some(&PlistValue::Bool(false)));
+        assert!(root.to_xml().ends_with("<dict><k>b</k><t /></dict></plist>"));
+    }
+
+    #[test]
+    fn malformed_plist() {
+        assert!(PlistDict::from_xml("").is_err());
+        assert!(PlistDict::from_xml("<plist><dict><k>a</k></dict></plist>").is_err());
+        assert!(PlistDict::from_xml("<plist><dict><k>a</k><s>b</dict></plist>").is_err());
+        assert!(PlistDict::from_xml("<plist><dict><k>a</k><i>b</i></dict></plist>").is_err());
+        assert!(PlistDict::from_xml("<plist><dict><k>a</k><s>&nope;</s></dict></plist>").is_err());
+    }
+}
@@ -2,7 +2,7 @@ use crate::serde::ser::error::Error;
 use dtoa::Float;
 use itoa::{Buffer, Integer};
 use serde::{
-    ser::{Error as _, Impossible, SerializeStruct},
+    ser::{Error as _, Impossible, SerializeMap, SerializeStruct},
     Serialize, Serializer,
 };
 use std::{fmt::Display, io::Write};
@@ -77,7 +77,7 @@ where
 impl<'a, W: Write> Serializer for &'a mut IndexedSerializer<W> {
     type Error = Error;
     type Ok = ();
-    type SerializeMap = Impossible<(), Error>;
+    type SerializeMap = Self;
     type SerializeSeq = Impossible<(), Error>;
     type SerializeStruct = Self;
     type SerializeStructVariant = Impossible<(), Error>;
@@ -173,11 +173,11 @@ impl<'a, W: Write> Serializer for &'a mut IndexedSerializer<W> {
         Err(Error::Unsupported("serialize_unit_variant"))
     }
 
-    fn serialize_newtype_struct<T: ?Sized>(self, _name: &'static str, _value: &T) -> Result<Self::Ok, Self::Error>
+    fn serialize_newtype_struct<T: ?Sized>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error>
     where
         T: Serialize,
     {
-        Err(Error::Unsupported("serialize_newtype_struct"))
+        value.serialize(self)
     }
 
     fn serialize_newtype_variant<T: ?Sized>(
@@ -208,7 +208,8 @@ impl<'a, W: Write> Serializer for &'a mut IndexedSerializer<W> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::Unsupported("serialize_map"))
+        // Used for structs that preserve unknown fields, which get serialized as maps with string keys
+        Ok(self)
     }
 
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
@@ -248,3 +249,29 @@ impl<'a, W: Write> SerializeStruct for &'a mut IndexedSerializer<W> {
         Ok(())
     }
 }
+
+impl<'a, W: Write> SerializeMap for &'a mut IndexedSerializer<W> {
+    type Error = Error;
+    type Ok = ();
+
+    fn serialize_key<T: ?Sized>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize,
+    {
+        if self.map_like {
+            key.serialize(&mut **self)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
@@ -2,4 +2,5 @@
 
 pub mod error;
 pub mod indexed;
+pub mod plist;
 pub mod request;
@@ -0,0 +1,294 @@
+//! Module containing the serializer for RobTop's plist dialect
+
+use super::error::Error;
+use crate::{
+    serde::{plist::is_element, PlistDict, PlistValue, ARRAY_MARKER, UNKNOWN_VALUE},
+    IntoOwned,
+};
+use serde::{
+    ser::{Impossible, SerializeMap, SerializeSeq, SerializeStruct},
+    Serialize, Serializer,
+};
+use std::{borrow::Cow, convert::TryFrom};
+
+/// Serializer for RobTop's plist dialect
+///
+/// Produces the [`PlistValue`] representing the serialized data, which can then be written as XML
+/// via [`PlistDict::to_xml`]. Since the game omits absent values from its dictionaries,
+/// serializing [`None`] results in no value at all (and the entry it would have been stored at
+/// being skipped).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PlistSerializer;
+
+impl PlistSerializer {
+    pub fn new() -> Self {
+        PlistSerializer
+    }
+}
+
+macro_rules! serialize_integer {
+    ($($method: ident => $t: ty),*) => {
+        $(
+            fn $method(self, v: $t) -> Result<Self::Ok, Self::Error> {
+                Ok(Some(PlistValue::Integer(v.into())))
+            }
+        )*
+    };
+}
+
+impl Serializer for PlistSerializer {
+    type Error = Error;
+    type Ok = Option<PlistValue<'static>>;
+    type SerializeMap = PlistDictSerializer;
+    type SerializeSeq = PlistArraySerializer;
+    type SerializeStruct = PlistDictSerializer;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+
+    serialize_integer! {
+        serialize_i8 => i8, serialize_i16 => i16, serialize_i32 => i32, serialize_i64 => i64,
+        serialize_u8 => u8, serialize_u16 => u16, serialize_u32 => u32
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        // The game omits booleans set to false instead of writing them as `<f />`
+        Ok(if v { Some(PlistValue::Bool(true)) } else { None })
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            // Integers outside of i64's range cannot be stored in an `<i>` tag
+            Err(_) => self.serialize_str(&v.to_string()),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        // Go through the shortest string representation, to avoid writing 0.1f32 as 0.10000000149011612
+        Ok(Some(PlistValue::Real(v.to_string().parse().unwrap_or_else(|_| v.into()))))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(PlistValue::Real(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(PlistValue::String(Cow::Owned(v.to_owned()))))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(Error::Unsupported("serialize_bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        match value.serialize(self)? {
+            // Restore the type of values stored as XML elements, see `PlistDeserializer::deserialize_ignored_any`
+            Some(PlistValue::String(element)) if name == UNKNOWN_VALUE && is_element(&element) => PlistValue::from_xml(&element)
+                .map(|value| Some(value.into_owned()))
+                .map_err(|err| Error::Custom(err.to_string())),
+            value => Ok(value),
+        }
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Error::Unsupported("serialize_newtype_variant"))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(PlistArraySerializer {
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(Error::Unsupported("serialize_tuple"))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(Error::Unsupported("serialize_tuple_struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(Error::Unsupported("serialize_tuple_variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(PlistDictSerializer::default())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(PlistDictSerializer::default())
+    }
+
+    fn serialize_struct_variant(
+        self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(Error::Unsupported("serialize_struct_variant"))
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct PlistDictSerializer {
+    dict: PlistDict<'static>,
+    key: Option<String>,
+}
+
+impl PlistDictSerializer {
+    fn push(&mut self, key: String, value: &(impl ?Sized + Serialize)) -> Result<(), Error> {
+        if let Some(value) = value.serialize(PlistSerializer)? {
+            self.dict.0.push((Cow::Owned(key), value));
+        }
+
+        Ok(())
+    }
+}
+
+impl SerializeStruct for PlistDictSerializer {
+    type Error = Error;
+    type Ok = Option<PlistValue<'static>>;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(PlistValue::Dict(self.dict)))
+    }
+}
+
+impl SerializeMap for PlistDictSerializer {
+    type Error = Error;
+    type Ok = Option<PlistValue<'static>>;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        match key.serialize(PlistSerializer)? {
+            Some(PlistValue::String(key)) => self.key = Some(key.into_owned()),
+            Some(PlistValue::Integer(key)) => self.key = Some(key.to_string()),
+            _ => return Err(Error::Custom("plist dictionary keys must be strings or integers".to_owned())),
+        }
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        match self.key.take() {
+            Some(key) => self.push(key, value),
+            None => Err(Error::Custom("serialize_value called before serialize_key".to_owned())),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(PlistValue::Dict(self.dict)))
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PlistArraySerializer {
+    elements: Vec<PlistValue<'static>>,
+}
+
+impl SerializeSeq for PlistArraySerializer {
+    type Error = Error;
+    type Ok = Option<PlistValue<'static>>;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        if let Some(value) = value.serialize(PlistSerializer)? {
+            self.elements.push(value);
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let mut array = PlistDict::new();
+
+        array.0.push((Cow::Borrowed(ARRAY_MARKER), PlistValue::Bool(true)));
+
+        for (idx, element) in self.elements.into_iter().enumerate() {
+            array.0.push((Cow::Owned(format!("k_{}", idx)), element));
+        }
+
+        Ok(Some(PlistValue::Dict(array)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{PlistDict, PlistSerializer, PlistValue};
+    use serde::Serialize;
+    use std::borrow::Cow;
+
+    #[derive(Serialize)]
+    struct Level<'a> {
+        k2: &'a str,
+        k18: u32,
+        k71: Option<u32>,
+        k87: f32,
+        k13: bool,
+        k14: bool,
+        tags: Vec<&'a str>,
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let level = Level {
+            k2: "Bloodbath",
+            k18: 5,
+            k71: None,
+            k87: 0.1,
+            k13: true,
+            k14: false,
+            tags: vec!["extreme", "demon"],
+        };
+
+        let value = level.serialize(PlistSerializer::new()).unwrap();
+        let dict = match value {
+            Some(PlistValue::Dict(dict)) => dict,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(dict.get("k71"), None);
+        assert_eq!(dict.get("k14"), None);
+        assert_eq!(dict.get("k87"), Some(&PlistValue::Real(0.1)));
+        assert_eq!(
+            dict.to_xml(),
+            r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>k2</k><s>Bloodbath</s><k>k18</k><i>5</i><k>k87</k><r>0.1</r><k>k13</k><t /><k>tags</k><d><k>_isArr</k><t /><k>k_0</k><s>extreme</s><k>k_1</k><s>demon</s></d></dict></plist>"#
+        );
+        assert_eq!(
+            PlistDict::from_xml(&dict.to_xml()).unwrap().get("k2"),
+            Some(&PlistValue::String(Cow::Borrowed("Bloodbath")))
+        );
+    }
+}
@@ -4,7 +4,7 @@ use serde::{ser::Error as _, Deserialize, Serialize, Serializer};
 use std::{
     borrow::{Borrow, Cow},
     mem,
-    num::ParseIntError,
+    num::{ParseFloatError, ParseIntError},
     str::Utf8Error,
     string::FromUtf8Error,
 };
@@ -42,11 +42,25 @@ pub enum ProcessError {
     #[error("{0}")]
     IntParse(#[from] ParseIntError),
 
+    /// Some error occurred when parsing a floating point number
+    #[error("{0}")]
+    FloatParse(#[from] ParseFloatError),
+
     /// Incorrect number of items when parsing a comma separated list (e.g. if an RGB list only has
     /// two entries)
     #[error("Incorrect number of items in comma separated list. Expected {expected}")]
     IncorrectLength { expected: usize },
 
+    /// A list of alternating keys and values (e.g. a level's song guidelines) contained an odd
+    /// number of items
+    #[error("Odd number of items in list of key-value pairs: {0}")]
+    OddLength(usize),
+
+    /// A relative time (such as "3 months") was missing its unit, or used a unit not known to
+    /// dash-rs
+    #[error("Unknown unit of time: '{0}'")]
+    UnknownTimeUnit(String),
+
     #[error("Received value that cannot be represented in Geometry Dash data format")]
     Unrepresentable,
 }
@@ -234,3 +248,31 @@ impl ThunkProcessor for Base64Decoder {
         output
     }
 }
+
+/// Processor for comma separated lists of IDs, such as the list of levels contained in a map pack
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, Copy)]
+pub struct IdList;
+
+impl ThunkProcessor for IdList {
+    type Error = ProcessError;
+    type Output<'a> = Vec<u64>;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        if unprocessed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        unprocessed
+            .split(',')
+            .map(|id| id.parse().map_err(ProcessError::IntParse))
+            .collect()
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        Ok(Cow::Owned(processed.iter().map(u64::to_string).collect::<Vec<_>>().join(",")))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
+}
@@ -0,0 +1,370 @@
+//! Module containing [`UnknownFields`], a container for all key/value pairs of a map-like data
+//! format that dash-rs does not (yet) map to a proper field, and the deserializer adapter that
+//! collects them
+
+use serde::{
+    de::{value::BorrowedStrDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{borrow::Cow, fmt::Formatter};
+
+use crate::serde::IntoOwned;
+
+/// The key/value pairs of some map-like Geometry Dash object that dash-rs did not recognize
+///
+/// Geometry Dash's data formats have many more indices than dash-rs maps to fields. To allow
+/// lossless round-tripping, objects such as [`LevelObject`](crate::model::level::object::LevelObject)
+/// keep all indices they do not understand here, in the order they were encountered, and write
+/// them back out verbatim when being serialized. If possible, the keys and values are borrowed from
+/// the input.
+///
+/// Values read from RobTop's plist dialect are stored as-is if they are strings. All other values
+/// (and strings that would be mistaken for one) are stored as their XML element (e.g. `<i>5</i>` or
+/// `<t />`), so that their type is preserved when writing them back out.
+#[derive(Debug, Clone, Default, Eq)]
+pub struct UnknownFields<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
+
+impl<'a> UnknownFields<'a> {
+    pub fn new() -> Self {
+        UnknownFields(Vec::new())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Gets the value associated with the given key, if present
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_ref())
+    }
+
+    /// Sets the value of the given key, replacing any previous value. New keys are appended at the
+    /// end.
+    pub fn insert(&mut self, key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
+        let key = key.into();
+        let value = value.into();
+
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old)) => *old = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    /// Removes the given key, returning its value if it was present
+    pub fn remove(&mut self, key: &str) -> Option<Cow<'a, str>> {
+        let position = self.0.iter().position(|(k, _)| k == key)?;
+
+        Some(self.0.remove(position).1)
+    }
+
+    /// Iterates over all key/value pairs, in the order they were read
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+}
+
+impl<'a> IntoOwned for UnknownFields<'a> {
+    type Owned = UnknownFields<'static>;
+
+    fn into_owned(self) -> UnknownFields<'static> {
+        UnknownFields(
+            self.0
+                .into_iter()
+                .map(|(k, v)| (Cow::Owned(k.into_owned()), Cow::Owned(v.into_owned())))
+                .collect(),
+        )
+    }
+}
+
+impl<'a, 'b> PartialEq<UnknownFields<'b>> for UnknownFields<'a> {
+    fn eq(&self, other: &UnknownFields<'b>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Serialize for UnknownFields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, &UnknownValue(value))?;
+        }
+
+        map.end()
+    }
+}
+
+/// The name of the newtype struct the values of [`UnknownFields`] are serialized as. Serializers
+/// that need to restore type information (such as the [`PlistSerializer`](crate::serde::PlistSerializer))
+/// look for it, all others treat it as a plain string.
+pub(crate) const UNKNOWN_VALUE: &str = "$dash_rs::UnknownValue";
+
+struct UnknownValue<'a>(&'a str);
+
+impl Serialize for UnknownValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(UNKNOWN_VALUE, self.0)
+    }
+}
+
+// Outside of Geometry Dash's data formats (e.g. when loading dash-rs' own JSON representation), we
+// always allocate
+impl<'de, 'a> Deserialize<'de> for UnknownFields<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct UnknownFieldsVisitor;
+
+        impl<'de> Visitor<'de> for UnknownFieldsVisitor {
+            type Value = UnknownFields<'static>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of strings to strings")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut fields = Vec::new();
+
+                while let Some((key, value)) = map.next_entry::<String, String>()? {
+                    fields.push((Cow::Owned(key), Cow::Owned(value)));
+                }
+
+                Ok(UnknownFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(UnknownFieldsVisitor)
+    }
+}
+
+/// [`Deserializer`] adapter that records all map entries its wrapped deserializer would otherwise
+/// skip (meaning all entries for which the deserialized struct called
+/// [`Deserializer::deserialize_ignored_any`]) into an [`UnknownFields`] instance.
+pub(crate) struct UnknownFieldsCollector<'s, 'de, D> {
+    deserializer: D,
+    sink: &'s mut UnknownFields<'de>,
+}
+
+impl<'s, 'de, D: Deserializer<'de>> UnknownFieldsCollector<'s, 'de, D> {
+    pub(crate) fn new(deserializer: D, sink: &'s mut UnknownFields<'de>) -> Self {
+        UnknownFieldsCollector { deserializer, sink }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method: ident($($arg: ident: $arg_type: ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $arg_type,)* visitor: V) -> Result<V::Value, Self::Error> {
+                self.deserializer.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'s, 'de, D: Deserializer<'de>> Deserializer<'de> for UnknownFieldsCollector<'s, 'de, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(); deserialize_bool(); deserialize_i8(); deserialize_i16(); deserialize_i32(); deserialize_i64();
+        deserialize_u8(); deserialize_u16(); deserialize_u32(); deserialize_u64(); deserialize_f32(); deserialize_f64();
+        deserialize_char(); deserialize_str(); deserialize_string(); deserialize_bytes(); deserialize_byte_buf();
+        deserialize_option(); deserialize_unit(); deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str); deserialize_seq(); deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]); deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserializer.deserialize_map(CollectingVisitor { visitor, sink: self.sink })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self, name: &'static str, fields: &'static [&'static str], visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserializer
+            .deserialize_struct(name, fields, CollectingVisitor { visitor, sink: self.sink })
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+struct CollectingVisitor<'s, 'de, V> {
+    visitor: V,
+    sink: &'s mut UnknownFields<'de>,
+}
+
+impl<'s, 'de, V: Visitor<'de>> Visitor<'de> for CollectingVisitor<'s, 'de, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        self.visitor.expecting(formatter)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        // List-like formats do not have keys, so there is nothing we could collect
+        self.visitor.visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.visitor.visit_map(CollectingMapAccess {
+            map,
+            sink: self.sink,
+            key: None,
+        })
+    }
+}
+
+struct CollectingMapAccess<'s, 'de, A> {
+    map: A,
+    sink: &'s mut UnknownFields<'de>,
+    /// The most recently read key
+    key: Option<Cow<'de, str>>,
+}
+
+impl<'s, 'de, A: MapAccess<'de>> MapAccess<'de> for CollectingMapAccess<'s, 'de, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let key = match self.map.next_key_seed(StrSeed)? {
+            Some(key) => key,
+            None => return Ok(None),
+        };
+
+        let value = match key {
+            Cow::Borrowed(key) => seed.deserialize(BorrowedStrDeserializer::new(key))?,
+            Cow::Owned(ref key) => seed.deserialize(key.as_str().into_deserializer())?,
+        };
+
+        self.key = Some(key);
+
+        Ok(Some(value))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        self.map.next_value_seed(CollectingSeed {
+            seed,
+            key: self.key.take(),
+            sink: self.sink,
+        })
+    }
+}
+
+/// [`DeserializeSeed`] that wraps the deserializer of a map value into a [`ValueCollector`]
+struct CollectingSeed<'s, 'de, T> {
+    seed: T,
+    key: Option<Cow<'de, str>>,
+    sink: &'s mut UnknownFields<'de>,
+}
+
+impl<'s, 'de, T: DeserializeSeed<'de>> DeserializeSeed<'de> for CollectingSeed<'s, 'de, T> {
+    type Value = T::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.seed.deserialize(ValueCollector {
+            deserializer,
+            key: self.key,
+            sink: self.sink,
+        })
+    }
+}
+
+struct ValueCollector<'s, 'de, D> {
+    deserializer: D,
+    key: Option<Cow<'de, str>>,
+    sink: &'s mut UnknownFields<'de>,
+}
+
+impl<'s, 'de, D: Deserializer<'de>> Deserializer<'de> for ValueCollector<'s, 'de, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(); deserialize_bool(); deserialize_i8(); deserialize_i16(); deserialize_i32(); deserialize_i64();
+        deserialize_u8(); deserialize_u16(); deserialize_u32(); deserialize_u64(); deserialize_f32(); deserialize_f64();
+        deserialize_char(); deserialize_str(); deserialize_string(); deserialize_bytes(); deserialize_byte_buf();
+        deserialize_option(); deserialize_unit(); deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str); deserialize_seq(); deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize); deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]); deserialize_identifier();
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let value = self.deserializer.deserialize_ignored_any(CowStrVisitor)?;
+
+        if let (Some(key), Some(value)) = (self.key, value) {
+            self.sink.0.push((key, value));
+        }
+
+        visitor.visit_unit()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+}
+
+struct StrSeed;
+
+impl<'de> DeserializeSeed<'de> for StrSeed {
+    type Value = Cow<'de, str>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer
+            .deserialize_str(CowStrVisitor)?
+            .ok_or_else(|| serde::de::Error::custom("expected a string key"))
+    }
+}
+
+/// Visitor that turns whatever value it is handed into a string, borrowing from the input if
+/// possible. A missing value (e.g. a key at the very end of the input) is visited as `None`.
+struct CowStrVisitor;
+
+impl<'de> Visitor<'de> for CowStrVisitor {
+    type Value = Option<Cow<'de, str>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Borrowed(if v { "1" } else { "0" })))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Borrowed(v)))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Some(Cow::Owned(v)))
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+}
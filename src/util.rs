@@ -79,6 +79,12 @@ pub(crate) fn true_to_ten<S: Serializer>(b: &bool, serializer: S) -> Result<S::O
     }
 }
 
+/// Whether the given value is equal to its type's default value. Used to omit such values when
+/// writing plist dictionaries, the same way the game does.
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
 #[macro_export]
 macro_rules! into_conversion {
     ($for:ty, $proxy_type:ty) => {
@@ -97,6 +103,23 @@ macro_rules! into_conversion {
     };
 }
 
+/// Implements [`IntoOwned`](crate::IntoOwned) for types that do not borrow any data, by simply
+/// returning the value itself
+#[macro_export]
+macro_rules! into_owned_identity {
+    ($($t: ty),*) => {
+        $(
+            impl $crate::IntoOwned for $t {
+                type Owned = $t;
+
+                fn into_owned(self) -> Self::Owned {
+                    self
+                }
+            }
+        )*
+    };
+}
+
 #[macro_export]
 macro_rules! dash_rs_newtype {
     ($name:ident) => {
@@ -118,5 +141,13 @@ macro_rules! dash_rs_newtype {
                 $name(Cow::Borrowed(from))
             }
         }
+
+        impl<'a> $crate::IntoOwned for $name<'a> {
+            type Owned = $name<'static>;
+
+            fn into_owned(self) -> $name<'static> {
+                $name(Cow::Owned(self.0.into_owned()))
+            }
+        }
     };
 }
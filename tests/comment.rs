@@ -19,6 +19,7 @@ impl framework::Testable for LevelCommentTester {
         if let Some(ref mut cnt) = target.special_color {
             cnt.process().unwrap();
         }
+        target.time_since_post.process().unwrap();
     }
 }
 
@@ -73,6 +74,7 @@ impl framework::Testable for ProfileCommentTester {
         if let Some(ref mut cnt) = target.content {
             cnt.process().unwrap();
         }
+        target.time_since_post.process().unwrap();
     }
 }
 
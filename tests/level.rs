@@ -1,6 +1,9 @@
 use std::path::Path;
 
-use dash_rs::model::level::Level;
+use dash_rs::{
+    model::level::{lazy::LazyObjects, CompressionScheme, Level, LevelData},
+    GJFormat, IntoOwned, Thunk,
+};
 use framework::load_test_units;
 
 mod framework;
@@ -44,8 +47,14 @@ impl framework::Testable for FullLevelTester {
         if let Some(ref mut hunk) = level.description {
             hunk.process().unwrap();
         }
-        level.level_data.level_data.process().unwrap();
+        let objects = level.level_data.level_data.process().unwrap();
+        objects.meta.guidelines.process().unwrap();
+        if let Some(ref mut color_channels) = objects.meta.color_channels {
+            color_channels.process().unwrap();
+        }
         level.level_data.password.process().unwrap();
+        level.level_data.time_since_upload.process().unwrap();
+        level.level_data.time_since_update.process().unwrap();
     }
 }
 
@@ -57,7 +66,58 @@ fn test_full_level() {
         println!("Testing case {:?}", path);
 
         unit.test_consistency();
-        // Cannot do round trip testing for onw, as the level data handling in dash-rs is incomplete
-        // (to put it nicely)
+        unit.test_save_load_roundtrip();
+        // Cannot do load/save round trip testing, as the level data is parsed into objects, which do
+        // not serialize back into the exact original object string. Compressing the unmodified
+        // object string does reproduce the original level data, see test_level_data_recompression.
     }
 }
+
+#[test]
+fn test_level_data_recompression() {
+    for id in ["897837", "11774780"] {
+        let raw = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("artifacts")
+                .join("level")
+                .join(id)
+                .join("raw"),
+        )
+        .unwrap();
+        let level: Level = Level::from_gj_str(&raw).unwrap();
+
+        let encoded = match level.level_data.level_data {
+            Thunk::Unprocessed(encoded) => encoded,
+            Thunk::Processed(_) => unreachable!(),
+        };
+        let lazy = LazyObjects::from_encoded(&encoded).unwrap();
+
+        assert_eq!(lazy.compression().scheme, CompressionScheme::Gzip { operating_system: 0x0b });
+        assert_eq!(lazy.compression().compress(lazy.as_str().as_bytes()).unwrap(), encoded);
+        assert_ne!(
+            lazy.compression().with_level(9).compress(lazy.as_str().as_bytes()).unwrap(),
+            encoded
+        );
+    }
+}
+
+#[test]
+fn test_level_into_owned() {
+    let mut level: Level<'static> = {
+        let raw = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("artifacts")
+                .join("level")
+                .join("897837")
+                .join("raw"),
+        )
+        .unwrap();
+
+        Level::<LevelData>::from_gj_str(&raw).unwrap().into_owned()
+    };
+
+    assert_eq!(level.level_id, 897837);
+    assert!(!level.level_data.level_data.process().unwrap().objects.is_empty());
+}